@@ -1,5 +1,14 @@
+use std::alloc::{self, Layout};
+use std::cmp::Ordering;
+use std::collections::{HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::{FusedIterator, Rev};
 use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
 use std::ptr::NonNull;
+use std::str::FromStr;
 
 /// A type alias for a nullable pointer to a `Node<T>`.
 ///
@@ -127,8 +136,13 @@ pub struct IterMut<'a, T: 'a> {
 /// The `Cursor` struct provides an iterator-like interface for traversing a `LinkedList` without modifying it.
 /// You can use it to access the elements of the list and move through the list sequentially.
 ///
+/// Besides the elements, a cursor can point to a "ghost" position that sits between the tail
+/// and the head of the list. Moving past either end of the list leads to the ghost position,
+/// and moving on from there wraps around to the other end.
+///
 /// # Fields
 /// - `index`: The current position of the cursor within the list, starting from 0.
+///   At the ghost position it is the length of the list.
 /// - `current`: A non-null pointer to the current node that the cursor is pointing to, or `None` if the cursor is at the end of the list.
 /// - `list`: A reference to the `LinkedList` being iterated over.
 ///
@@ -136,7 +150,7 @@ pub struct IterMut<'a, T: 'a> {
 /// ```
 /// use linked_list::LinkedList;
 ///
-/// let mut list = LinkedList::from([1, 2, 3]);
+/// let list = LinkedList::from([1, 2, 3]);
 /// let mut cursor = list.cursor_front();
 /// while let Some(current) = cursor.current() {
 ///     println!("{}", current);
@@ -156,6 +170,7 @@ pub struct Cursor<'a, T: 'a> {
 ///
 /// # Fields
 /// - `index`: The current position of the cursor within the list, starting from 0.
+///   At the ghost position it is the length of the list.
 /// - `current`: A non-null pointer to the current node that the cursor is pointing to, or `None` if the cursor is at the end of the list.
 /// - `list`: A mutable reference to the `LinkedList` being iterated over, allowing modification of its nodes.
 ///
@@ -182,6 +197,184 @@ pub struct CursorMut<'a, T: 'a> {
     list: &'a mut LinkedList<T>,
 }
 
+/// An iterator that removes the elements matching a predicate from a `LinkedList`.
+///
+/// This struct is created by [`LinkedList::extract_if`]. Each matching node is unlinked
+/// at the moment its element is yielded, so dropping the iterator early leaves the
+/// remaining elements in the list.
+///
+/// # Fields
+/// - `list`: A mutable reference to the `LinkedList` being filtered.
+/// - `current`: A pointer to the next node to test, or `None` if the iteration has finished.
+/// - `remaining`: The number of nodes that have not been tested yet.
+/// - `pred`: The predicate deciding which elements are removed.
+///
+/// # Example
+/// ```
+/// use linked_list::LinkedList;
+///
+/// let mut list = LinkedList::from([1, 2, 3, 4, 5, 6]);
+/// let evens = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
+///
+/// assert_eq!(evens, vec![2, 4, 6]);
+/// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 3, 5]);
+/// ```
+pub struct ExtractIf<'a, T: 'a, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    list: &'a mut LinkedList<T>,
+    current: Link<T>,
+    remaining: usize,
+    pred: F,
+}
+
+/// A draining iterator over a range of elements of a `LinkedList`.
+///
+/// This struct is created by [`LinkedList::drain`] and [`LinkedList::splice`]. The drained
+/// nodes are detached from the list when the iterator is created, the iterator owns them
+/// and drops the elements that were not yielded.
+///
+/// # Fields
+/// - `list`: The detached nodes that have not been yielded yet.
+/// - `_marker`: A marker to indicate the mutable borrow of the source list.
+///
+/// # Example
+/// ```
+/// use linked_list::LinkedList;
+///
+/// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+/// let drained = list.drain(1..4).collect::<Vec<_>>();
+///
+/// assert_eq!(drained, vec![2, 3, 4]);
+/// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 5]);
+/// ```
+pub struct Drain<'a, T: 'a> {
+    list: LinkedList<T>,
+    _marker: PhantomData<&'a mut LinkedList<T>>,
+}
+
+/// An error returned when an index is out of the bounds of a `LinkedList`.
+///
+/// This error is returned by [`LinkedList::try_remove`] and by the `seek` methods of
+/// [`Cursor`] and [`CursorMut`].
+///
+/// # Fields
+/// - `index`: The index that was requested.
+/// - `len`: The length of the list at the time of the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IndexError {
+    pub index: usize,
+    pub len: usize,
+}
+
+/// An error returned when a cursor cannot be moved by the requested offset.
+///
+/// This error is returned by [`Cursor::move_by`] and [`CursorMut::move_by`] when the
+/// move would go past either end of the list. The cursor is left where it was.
+///
+/// # Fields
+/// - `offset`: The offset that was requested.
+/// - `index`: The index of the cursor, or `None` if it was at the ghost position.
+/// - `len`: The length of the list at the time of the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MoveError {
+    pub offset: isize,
+    pub index: Option<usize>,
+    pub len: usize,
+}
+
+/// An error returned when an element cannot be inserted into a `LinkedList`.
+///
+/// This error is returned by [`LinkedList::try_insert`] and gives the rejected element
+/// back to the caller.
+///
+/// # Fields
+/// - `element`: The element that was not inserted.
+/// - `kind`: The reason why the insertion failed. For an out-of-bounds insertion, the
+///   requested `index` and the `len` of the list are kept in [`InsertErrorKind::OutOfBounds`].
+///
+/// # Example
+/// ```
+/// use linked_list::{InsertErrorKind, LinkedList};
+///
+/// let mut list = LinkedList::from([1, 2]);
+/// let err = list.try_insert(4, 3).unwrap_err();
+///
+/// let InsertErrorKind::OutOfBounds { index, len } = err.kind else {
+///     panic!("the index is out of bounds");
+/// };
+/// assert_eq!((index, len), (4, 2));
+/// assert_eq!(err.element, 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InsertError<T> {
+    pub element: T,
+    pub kind: InsertErrorKind,
+}
+
+/// The reason why an element could not be inserted into a `LinkedList`.
+///
+/// # Variants
+/// - `OutOfBounds`: The requested `index` is greater than the `len` of the list.
+/// - `AllocFailed`: The memory for the new node could not be allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InsertErrorKind {
+    OutOfBounds { index: usize, len: usize },
+    AllocFailed,
+}
+
+/// An error returned when the memory for a new node cannot be allocated.
+///
+/// This error is returned by the fallible insertion methods such as
+/// [`LinkedList::try_push_back`] and gives the rejected element back to the caller.
+///
+/// # Fields
+/// - `element`: The element that was not inserted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AllocError<T> {
+    pub element: T,
+}
+
+/// An error returned when a `LinkedList` cannot be parsed from a string.
+///
+/// This error is returned by the [`FromStr`] implementation of `LinkedList<T>`.
+///
+/// # Variants
+/// - `MissingBrackets`: The input is not enclosed in `[` and `]`.
+/// - `Element`: The element at `index` could not be parsed, `error` is the error returned
+///   by `T::from_str`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseListError<E> {
+    MissingBrackets,
+    Element { index: usize, error: E },
+}
+
+/// A helper struct for formatting a `LinkedList` with custom delimiters.
+///
+/// This struct is created by [`LinkedList::display_with`].
+///
+/// # Fields
+/// - `list`: The list being formatted.
+/// - `separator`: The string written between two elements.
+/// - `open`: The string written before the first element.
+/// - `close`: The string written after the last element.
+///
+/// # Example
+/// ```
+/// use linked_list::LinkedList;
+///
+/// let list = LinkedList::from([1, 2, 3]);
+///
+/// assert_eq!(list.display_with(" -> ", "(", ")").to_string(), "(1 -> 2 -> 3)");
+/// ```
+pub struct DisplayWith<'a, T: 'a> {
+    list: &'a LinkedList<T>,
+    separator: &'a str,
+    open: &'a str,
+    close: &'a str,
+}
+
 impl<T> Node<T> {
     /// Creates a new node with the given element.
     ///
@@ -194,6 +387,42 @@ impl<T> Node<T> {
             prev: None,
         }
     }
+
+    /// Allocates a new unlinked node with the given element on the heap.
+    ///
+    /// The allocation goes through [`std::alloc::alloc`] so a failure can be reported to the
+    /// caller. Returns the element back if the memory could not be allocated.
+    ///
+    /// # Arguments
+    /// * `element` - The value to be stored in the node.
+    fn try_boxed(element: T) -> Result<Box<Self>, T> {
+        let layout = Layout::new::<Self>();
+
+        unsafe {
+            let ptr = alloc::alloc(layout).cast::<Self>();
+
+            if ptr.is_null() {
+                return Err(element);
+            }
+
+            ptr.write(Self::new(element));
+            Ok(Box::from_raw(ptr))
+        }
+    }
+
+    /// Allocates a new unlinked node with the given element on the heap.
+    ///
+    /// Calls [`std::alloc::handle_alloc_error`] if the memory could not be allocated,
+    /// which is the behaviour of [`Box::new`].
+    ///
+    /// # Arguments
+    /// * `element` - The value to be stored in the node.
+    fn boxed(element: T) -> Box<Self> {
+        match Self::try_boxed(element) {
+            Ok(node) => node,
+            Err(_) => alloc::handle_alloc_error(Layout::new::<Self>()),
+        }
+    }
 }
 
 /// A doubly linked list implementation.
@@ -253,7 +482,29 @@ impl<T> LinkedList<T> {
     /// # Arguments
     /// * `value` - The value to insert at the front.
     pub fn push_front(&mut self, value: T) {
-        let new_head = NonNull::from(Box::leak(Box::new(Node::new(value))));
+        self.push_front_node(Node::boxed(value));
+    }
+
+    /// Adds an element to the front of the list, returning it back if the allocation fails.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// assert!(list.try_push_front(1).is_ok());
+    /// assert_eq!(list.front(), Some(&1));
+    /// ```
+    pub fn try_push_front(&mut self, value: T) -> Result<(), AllocError<T>> {
+        let node = Node::try_boxed(value).map_err(|element| AllocError { element })?;
+        self.push_front_node(node);
+        Ok(())
+    }
+
+    /// Links an unlinked node at the front of the list.
+    fn push_front_node(&mut self, node: Box<Node<T>>) {
+        let new_head = NonNull::from(Box::leak(node));
 
         match self.head.take() {
             None => {
@@ -283,7 +534,29 @@ impl<T> LinkedList<T> {
     /// assert_eq!(list.front(), Some(&10));
     /// ```
     pub fn push_back(&mut self, value: T) {
-        let new_tail = NonNull::from(Box::leak(Box::new(Node::new(value))));
+        self.push_back_node(Node::boxed(value));
+    }
+
+    /// Adds an element to the back of the list, returning it back if the allocation fails.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// assert!(list.try_push_back(1).is_ok());
+    /// assert_eq!(list.back(), Some(&1));
+    /// ```
+    pub fn try_push_back(&mut self, value: T) -> Result<(), AllocError<T>> {
+        let node = Node::try_boxed(value).map_err(|element| AllocError { element })?;
+        self.push_back_node(node);
+        Ok(())
+    }
+
+    /// Links an unlinked node at the back of the list.
+    fn push_back_node(&mut self, node: Box<Node<T>>) {
+        let new_tail = NonNull::from(Box::leak(node));
 
         match self.tail.take() {
             None => {
@@ -312,6 +585,11 @@ impl<T> LinkedList<T> {
     /// list.push_back(20);
     /// assert_eq!(list.back(), Some(&20));
     pub fn pop_front(&mut self) -> Option<T> {
+        self.pop_front_node().map(|node| node.element)
+    }
+
+    /// Unlinks the first node of the list and returns ownership of it.
+    fn pop_front_node(&mut self) -> Option<Box<Node<T>>> {
         self.head.take().map(|old_head| unsafe {
             let mut old_head = Box::from_raw(old_head.as_ptr());
 
@@ -327,7 +605,7 @@ impl<T> LinkedList<T> {
             };
 
             self.len -= 1;
-            old_head.element
+            old_head
         })
     }
 
@@ -412,6 +690,10 @@ impl<T> LinkedList<T> {
 
     /// Clears all elements from the `LinkedList`.
     ///
+    /// The nodes are detached from the list before any element is dropped, so the list is
+    /// empty even if the destructor of an element panics. The remaining elements are still
+    /// dropped in that case.
+    ///
     /// # Example
     /// ```
     /// use linked_list::LinkedList;
@@ -423,7 +705,7 @@ impl<T> LinkedList<T> {
     /// ```
     #[inline]
     pub fn clear(&mut self) {
-        while self.pop_back().is_some() {}
+        drop(std::mem::take(self));
     }
 
     /// Returns an iterator over the elements of the list.
@@ -443,7 +725,7 @@ impl<T> LinkedList<T> {
     /// # Returns
     /// A ['Iter<T>'] immutable iterator for iterate over the values of the linked list
     #[inline]
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             head: self.head,
             tail: self.tail,
@@ -471,7 +753,7 @@ impl<T> LinkedList<T> {
     /// # Returns
     /// A ['IterMut<T>'] mutable iterator for iterate over the values of the linked list
     #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut {
             head: self.head,
             tail: self.tail,
@@ -480,6 +762,35 @@ impl<T> LinkedList<T> {
         }
     }
 
+    /// Returns an adapter that formats the list with the given separator and delimiters.
+    ///
+    /// Every element is formatted with its [`fmt::Display`] implementation. The default
+    /// [`fmt::Display`] implementation of the list is `display_with(", ", "[", "]")`.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from([1, 2, 3]);
+    ///
+    /// assert_eq!(list.display_with("; ", "{", "}").to_string(), "{1; 2; 3}");
+    /// assert_eq!(list.to_string(), "[1, 2, 3]");
+    /// ```
+    #[inline]
+    pub fn display_with<'a>(
+        &'a self,
+        separator: &'a str,
+        open: &'a str,
+        close: &'a str,
+    ) -> DisplayWith<'a, T> {
+        DisplayWith {
+            list: self,
+            separator,
+            open,
+            close,
+        }
+    }
+
     /// Returns a cursor positioned at the front of the list for immutable traversal.
     ///
     /// The returned [`Cursor`] allows iteration over the list without modifying it.
@@ -488,14 +799,14 @@ impl<T> LinkedList<T> {
     /// ```
     /// use linked_list::LinkedList;
     ///
-    /// let mut list: LinkedList<i32> = LinkedList::new();
+    /// let list: LinkedList<i32> = LinkedList::new();
     /// let cursor = list.cursor_front();
     /// ```
     ///
     /// # Returns
     /// A [`Cursor`] positioned at the first element of the list, or an empty cursor if the list is empty.
     #[inline]
-    pub fn cursor_front(&mut self) -> Cursor<T> {
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
         Cursor {
             index: 0,
             current: self.head,
@@ -511,14 +822,14 @@ impl<T> LinkedList<T> {
     /// ```
     /// use linked_list::LinkedList;
     ///
-    /// let mut list: LinkedList<i32> = LinkedList::new();
+    /// let list: LinkedList<i32> = LinkedList::new();
     /// let cursor = list.cursor_back();
     /// ```
     ///
     /// # Returns
     /// A [`Cursor`] positioned at the last element of the list, or an empty cursor if the list is empty.
     #[inline]
-    pub fn cursor_back(&mut self) -> Cursor<T> {
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
         Cursor {
             index: self.len.saturating_sub(1),
             current: self.tail,
@@ -540,7 +851,7 @@ impl<T> LinkedList<T> {
     /// # Returns
     /// A [`CursorMut`] positioned at the first element of the list, or an empty cursor if the list is empty.
     #[inline]
-    pub fn cursor_front_mut(&mut self) -> CursorMut<T> {
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
         CursorMut {
             index: 0,
             current: self.head,
@@ -562,7 +873,7 @@ impl<T> LinkedList<T> {
     /// # Returns
     /// A [`CursorMut`] positioned at the last element of the list, or an empty cursor if the list is empty.
     #[inline]
-    pub fn cursor_back_mut(&mut self) -> CursorMut<T> {
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
         CursorMut {
             index: self.len.saturating_sub(1),
             current: self.tail,
@@ -600,826 +911,6276 @@ impl<T> LinkedList<T> {
     pub fn contains(&self, value: &T) -> bool where T: PartialEq<T> {
         self.iter().any(|current| current == value)
     }
-}
 
-impl<T> Default for LinkedList<T> {
-    #[inline]
-    fn default() -> Self {
-        LinkedList::new()
+    /// Returns a reference to the element at the given index, if any.
+    ///
+    /// The element is found by walking from whichever end of the list is closer to `index`.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from([1, 2, 3]);
+    ///
+    /// assert_eq!(list.get(1), Some(&2));
+    /// assert_eq!(list.get(3), None);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(min(index, len - index))**
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.find_node(index)
+            .map(|node| unsafe { &(*node.as_ptr()).element })
     }
-}
-
-impl<T> IntoIterator for LinkedList<T> {
-    type Item = T;
-    type IntoIter = IntoIter<T>;
 
-    #[inline]
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIter { list: self }
+    /// Returns a mutable reference to the element at the given index, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    ///
+    /// if let Some(element) = list.get_mut(1) {
+    ///     *element = 20;
+    /// }
+    ///
+    /// assert_eq!(list.get(1), Some(&20));
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(min(index, len - index))**
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.find_node(index)
+            .map(|node| unsafe { &mut (*node.as_ptr()).element })
     }
-}
 
-impl<T> Drop for LinkedList<T> {
-    #[inline]
-    fn drop(&mut self) {
-        while self.pop_back().is_some() {}
-    }
-}
+    /// Inserts an element at the given index, shifting all elements after it.
+    ///
+    /// # Panics
+    /// Panics if `index > len`, see [`LinkedList::try_insert`] for a non-panicking variant.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 3]);
+    /// list.insert(1, 2);
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(min(index, len - index))**
+    pub fn insert(&mut self, index: usize, element: T) {
+        let len = self.len;
+        assert!(index <= len, "insertion index (is {index}) should be <= len (is {len})");
 
-impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
-    fn from(array: [T; N]) -> Self {
-        let mut list = Self::default();
-        for elem in array {
-            list.push_back(elem);
-        }
-        list
+        self.insert_node(index, Node::boxed(element));
     }
-}
 
-impl<E> FromIterator<E> for LinkedList<E> {
-    fn from_iter<T: IntoIterator<Item = E>>(iter: T) -> Self {
-        let mut list = Self::default();
-        for elem in iter {
-            list.push_back(elem);
+    /// Inserts an element at the given index, returning it back on failure.
+    ///
+    /// The insertion fails if the index is out of bounds or if the memory for the new node
+    /// could not be allocated, [`InsertError::kind`] tells the two cases apart.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::{InsertErrorKind, LinkedList};
+    ///
+    /// let mut list = LinkedList::from([1, 2]);
+    ///
+    /// assert!(list.try_insert(2, 3).is_ok());
+    ///
+    /// let err = list.try_insert(5, 4).unwrap_err();
+    /// assert_eq!(err.element, 4);
+    /// assert_eq!(err.kind, InsertErrorKind::OutOfBounds { index: 5, len: 3 });
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(min(index, len - index))**
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), InsertError<T>> {
+        if index > self.len {
+            return Err(InsertError {
+                element,
+                kind: InsertErrorKind::OutOfBounds {
+                    index,
+                    len: self.len,
+                },
+            });
         }
-        list
-    }
-}
 
-impl<'a, T: 'a> Iterator for Iter<'a, T> {
-    type Item = &'a T;
+        let node = Node::try_boxed(element).map_err(|element| InsertError {
+            element,
+            kind: InsertErrorKind::AllocFailed,
+        })?;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.len == 0 {
-            None
-        } else {
-            self.head.map(|node| unsafe {
-                let node = &*node.as_ptr();
-                self.head = node.next;
-                self.len -= 1;
-                &node.element
-            })
-        }
+        self.insert_node(index, node);
+        Ok(())
     }
-}
 
-impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T> {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.len == 0 {
-            None
-        } else {
-            self.tail.map(|node| unsafe {
-                let node = &*node.as_ptr();
-                self.len -= 1;
-                self.tail = node.prev;
-                &node.element
-            })
+    /// Removes and returns the element at the given index, shifting all elements after it.
+    ///
+    /// # Panics
+    /// Panics if `index >= len`, see [`LinkedList::try_remove`] for a non-panicking variant.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    ///
+    /// assert_eq!(list.remove(1), 2);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 3]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(min(index, len - index))**
+    pub fn remove(&mut self, index: usize) -> T {
+        match self.try_remove(index) {
+            Ok(element) => element,
+            Err(err) => panic!("removal index (is {}) should be < len (is {})", err.index, err.len),
         }
     }
-}
-
-impl<'a, T: 'a> Iterator for IterMut<'a, T> {
-    type Item = &'a mut T;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.len == 0 {
+    /// Removes and returns the element at the given index, or an error if it is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    ///
+    /// assert_eq!(list.try_remove(0), Ok(1));
+    /// assert!(list.try_remove(2).is_err());
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(min(index, len - index))**
+    pub fn try_remove(&mut self, index: usize) -> Result<T, IndexError> {
+        match self.find_node(index) {
+            None => Err(IndexError {
+                index,
+                len: self.len,
+            }),
+            Some(node) => Ok(unsafe { self.unlink_node(node).element }),
+        }
+    }
+
+    /// Removes the element at the given index and replaces it with the first element.
+    ///
+    /// The first node is relinked into the position of the removed one, so the remaining
+    /// elements are not moved. Returns `None` if `index` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(list.swap_remove_front(2), Some(3));
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![2, 1, 4]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(min(index, len - index))**
+    pub fn swap_remove_front(&mut self, index: usize) -> Option<T> {
+        let node = self.find_node(index)?;
+
+        if Some(node) == self.head {
+            return self.pop_front();
+        }
+
+        unsafe {
+            let next = node.as_ref().next;
+            let removed = self.unlink_node(node);
+            let first = self.pop_front_node()?;
+            let prev = match next {
+                None => self.tail,
+                Some(next) => next.as_ref().prev,
+            };
+
+            self.link_node(prev, next, first);
+            Some(removed.element)
+        }
+    }
+
+    /// Removes the element at the given index and replaces it with the last element.
+    ///
+    /// The last node is relinked into the position of the removed one, so the remaining
+    /// elements are not moved. Returns `None` if `index` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(list.swap_remove_back(1), Some(2));
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 4, 3]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(min(index, len - index))**
+    pub fn swap_remove_back(&mut self, index: usize) -> Option<T> {
+        let node = self.find_node(index)?;
+
+        if Some(node) == self.tail {
+            return self.pop_back();
+        }
+
+        unsafe {
+            let prev = node.as_ref().prev;
+            let removed = self.unlink_node(node);
+            let last = self.unlink_node(self.tail?);
+            let next = match prev {
+                None => self.head,
+                Some(prev) => prev.as_ref().next,
+            };
+
+            self.link_node(prev, next, last);
+            Some(removed.element)
+        }
+    }
+
+    /// Swaps the elements at the given indices by relinking their nodes.
+    ///
+    /// The elements themselves are not moved in memory, only the `next` and `prev` pointers
+    /// around the two nodes are updated.
+    ///
+    /// # Panics
+    /// Panics if either index is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4]);
+    /// list.swap(0, 2);
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![3, 2, 1, 4]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(min(i, len - i) + min(j, len - j))**
+    pub fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < self.len, "index (is {i}) should be < len (is {})", self.len);
+        assert!(j < self.len, "index (is {j}) should be < len (is {})", self.len);
+
+        if i == j {
+            return;
+        }
+
+        let (i, j) = if i < j { (i, j) } else { (j, i) };
+
+        let (Some(a), Some(b)) = (self.find_node(i), self.find_node(j)) else {
+            unreachable!("both indices are within the bounds of the list");
+        };
+
+        unsafe {
+            let a_prev = a.as_ref().prev;
+            let b_next = b.as_ref().next;
+
+            if a.as_ref().next == Some(b) {
+                // the nodes are adjacent: `a_prev <-> b <-> a <-> b_next`
+                (*b.as_ptr()).prev = a_prev;
+                (*b.as_ptr()).next = Some(a);
+                (*a.as_ptr()).prev = Some(b);
+                (*a.as_ptr()).next = b_next;
+            } else {
+                let a_next = a.as_ref().next;
+                let b_prev = b.as_ref().prev;
+
+                (*a.as_ptr()).prev = b_prev;
+                (*a.as_ptr()).next = b_next;
+                (*b.as_ptr()).prev = a_prev;
+                (*b.as_ptr()).next = a_next;
+
+                if let Some(a_next) = a_next {
+                    (*a_next.as_ptr()).prev = Some(b);
+                }
+
+                if let Some(b_prev) = b_prev {
+                    (*b_prev.as_ptr()).next = Some(a);
+                }
+            }
+
+            match a_prev {
+                None => self.head = Some(b),
+                Some(a_prev) => (*a_prev.as_ptr()).next = Some(b),
+            }
+
+            match b_next {
+                None => self.tail = Some(a),
+                Some(b_next) => (*b_next.as_ptr()).prev = Some(a),
+            }
+        }
+    }
+
+    /// Moves all elements from `other` to the end of the list.
+    ///
+    /// The nodes of `other` are relinked into `self`, no element is moved or reallocated.
+    /// After this operation `other` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut first = LinkedList::from([1, 2]);
+    /// let mut second = LinkedList::from([3, 4]);
+    ///
+    /// first.append(&mut second);
+    ///
+    /// assert_eq!(first.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// assert!(second.is_empty());
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(1)**
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        match self.tail {
+            None => std::mem::swap(self, other),
+            Some(tail) => {
+                if let Some(other_head) = other.head.take() {
+                    unsafe {
+                        (*tail.as_ptr()).next = Some(other_head);
+                        (*other_head.as_ptr()).prev = Some(tail);
+                    }
+
+                    self.tail = other.tail.take();
+                    self.len += std::mem::replace(&mut other.len, 0);
+                }
+            }
+        }
+    }
+
+    /// Moves all elements from `other` to the front of the list.
+    ///
+    /// The nodes of `other` are relinked into `self`, no element is moved or reallocated.
+    /// After this operation `other` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut first = LinkedList::from([3, 4]);
+    /// let mut second = LinkedList::from([1, 2]);
+    ///
+    /// first.prepend(&mut second);
+    ///
+    /// assert_eq!(first.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// assert!(second.is_empty());
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(1)**
+    pub fn prepend(&mut self, other: &mut LinkedList<T>) {
+        match self.head {
+            None => std::mem::swap(self, other),
+            Some(head) => {
+                if let Some(other_tail) = other.tail.take() {
+                    unsafe {
+                        (*head.as_ptr()).prev = Some(other_tail);
+                        (*other_tail.as_ptr()).next = Some(head);
+                    }
+
+                    self.head = other.head.take();
+                    self.len += std::mem::replace(&mut other.len, 0);
+                }
+            }
+        }
+    }
+
+    /// Splits the list into two at the given index.
+    ///
+    /// Returns a new list containing the elements in the range `[at, len)`, the list keeps
+    /// the elements in the range `[0, at)`. The split point is found by walking from
+    /// whichever end of the list is closer to `at`.
+    ///
+    /// # Panics
+    /// Panics if `at > len`.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4]);
+    /// let tail = list.split_off(1);
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1]);
+    /// assert_eq!(tail.into_iter().collect::<Vec<_>>(), vec![2, 3, 4]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(min(at, len - at))**
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        let len = self.len;
+        assert!(at <= len, "cannot split off at a nonexistent index");
+
+        if at == 0 {
+            return std::mem::take(self);
+        }
+
+        if at == len {
+            return LinkedList::new();
+        }
+
+        let split_node = self.find_node(at - 1);
+        unsafe { self.split_off_after_node(split_node, at) }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// Removes every element `e` for which `f(&e)` returns `false`, in a single pass from
+    /// the front of the list. The order of the retained elements is preserved.
+    ///
+    /// If `f` panics, the elements tested so far have already been removed or kept and
+    /// the list stays valid.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4]);
+    /// list.retain(|x| *x % 2 == 0);
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![2, 4]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n)**
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|element| f(element));
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable reference to it.
+    ///
+    /// Works like [`LinkedList::retain`] but allows the predicate to modify the elements.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4]);
+    /// list.retain_mut(|x| {
+    ///     *x *= 10;
+    ///     *x > 20
+    /// });
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![30, 40]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n)**
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.extract_if(|element| !f(element)).for_each(drop);
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element should be removed.
+    ///
+    /// If the closure returns `true`, the element is unlinked from the list and yielded.
+    /// If the closure returns `false`, the element remains in the list. The iterator is lazy:
+    /// elements that have not been reached when it is dropped stay in the list.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let mut odds = list.extract_if(|x| *x % 2 == 1);
+    ///
+    /// assert_eq!(odds.next(), Some(1));
+    /// drop(odds);
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+    /// ```
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        ExtractIf {
+            current: self.head,
+            remaining: self.len,
+            list: self,
+            pred,
+        }
+    }
+
+    /// Removes consecutive repeated elements from the list.
+    ///
+    /// If the list is sorted, this removes all duplicates.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 1, 2, 3, 3, 3, 1]);
+    /// list.dedup();
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 1]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n)**
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements that resolve to the same key.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([10, 11, 20, 21, 30, 10]);
+    /// list.dedup_by_key(|x| *x / 10);
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![10, 20, 30, 10]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n)**
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes consecutive elements satisfying the given equality relation.
+    ///
+    /// `same_bucket` receives the current element and the last retained element before it,
+    /// in that order. If it returns `true`, the current element is unlinked and dropped.
+    /// The traversal is done in a single forward pass.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from(["foo", "FOO", "bar", "Bar", "baz"]);
+    /// list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec!["foo", "bar", "baz"]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n)**
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let Some(mut retained) = self.head else {
+            return;
+        };
+
+        unsafe {
+            let mut current = retained.as_ref().next;
+
+            while let Some(node) = current {
+                current = node.as_ref().next;
+
+                if same_bucket(&mut (*node.as_ptr()).element, &mut (*retained.as_ptr()).element) {
+                    drop(self.unlink_node(node));
+                } else {
+                    retained = node;
+                }
+            }
+        }
+    }
+
+    /// Removes every element that is equal to an element before it, wherever it is.
+    ///
+    /// Unlike [`LinkedList::dedup`], the list does not need to be sorted. The first occurrence
+    /// of every element is kept, so the order in which the elements were first seen is
+    /// preserved. The retained elements are tracked in a [`HashSet`] of references.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([3, 1, 3, 2, 1, 4]);
+    /// list.dedup_all();
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![3, 1, 2, 4]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n)** expected time and **O(n)** extra memory.
+    pub fn dedup_all(&mut self)
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::with_capacity(self.len);
+        let mut current = self.head;
+
+        while let Some(node) = current {
+            unsafe {
+                current = node.as_ref().next;
+
+                // the retained nodes are never freed while `seen` is alive
+                let element: &T = &(*node.as_ptr()).element;
+
+                if !seen.insert(element) {
+                    drop(self.unlink_node(node));
+                }
+            }
+        }
+    }
+
+    /// Removes the elements in the given range from the list and returns them as an iterator.
+    ///
+    /// The boundaries of the range are located from whichever end of the list is closer,
+    /// and the removed nodes are detached by relinking the surrounding nodes once.
+    /// Elements that are not consumed from the returned iterator are dropped with it.
+    ///
+    /// # Panics
+    /// Panics if the start of the range is greater than its end or if the end of the range
+    /// is greater than the length of the list.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let mut drain = list.drain(1..=3);
+    ///
+    /// assert_eq!(drain.len(), 3);
+    /// assert_eq!(drain.next_back(), Some(4));
+    /// drop(drain);
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 5]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(min(start, len - start) + min(end, len - end))** to detach the range.
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = resolve_range(range, self.len);
+
+        Drain {
+            list: self.detach_range(start, end),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Replaces the elements in the given range with the elements of `replace_with`.
+    ///
+    /// The replacement elements are collected first, then the range is detached and the new
+    /// nodes are linked in its place. Returns an iterator over the removed elements.
+    ///
+    /// # Panics
+    /// Panics if the start of the range is greater than its end or if the end of the range
+    /// is greater than the length of the list.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4]);
+    /// let removed = list.splice(1..3, [7, 8, 9]).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(removed, vec![2, 3]);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 7, 8, 9, 4]);
+    /// ```
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let (start, end) = resolve_range(range, self.len);
+        let replacement = replace_with.into_iter().collect::<LinkedList<T>>();
+
+        let (removed, prev, next) = self.detach_range_with_neighbours(start, end);
+        unsafe { self.splice_nodes(prev, next, replacement) };
+
+        Drain {
+            list: removed,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Reverses the order of the elements in the list, in place.
+    ///
+    /// The `next` and `prev` pointers of every node are swapped, no element is moved and
+    /// no memory is allocated.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// list.reverse();
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n)**
+    pub fn reverse(&mut self) {
+        let mut current = self.head;
+
+        while let Some(node) = current {
+            unsafe {
+                let node = &mut *node.as_ptr();
+                std::mem::swap(&mut node.next, &mut node.prev);
+                current = node.prev;
+            }
+        }
+
+        std::mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Reverses the order of the elements in the given range, in place.
+    ///
+    /// The boundaries of the range are located from whichever end of the list is closer,
+    /// the nodes inside the range have their `next` and `prev` pointers swapped and the range
+    /// is then linked back between its surrounding nodes.
+    ///
+    /// # Panics
+    /// Panics if the start of the range is greater than its end or if the end of the range
+    /// is greater than the length of the list.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// list.reverse_range(1..4);
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 4, 3, 2, 5]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(end - start)** plus the cost of locating the boundaries.
+    pub fn reverse_range<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = resolve_range(range, self.len);
+
+        if end - start < 2 {
+            return;
+        }
+
+        let (Some(first), Some(last)) = (self.find_node(start), self.find_node(end - 1)) else {
+            unreachable!("the range is within the bounds of the list");
+        };
+
+        unsafe {
+            let before = first.as_ref().prev;
+            let after = last.as_ref().next;
+
+            let mut current = Some(first);
+            while let Some(node) = current {
+                let node = &mut *node.as_ptr();
+                std::mem::swap(&mut node.next, &mut node.prev);
+                current = if current == Some(last) { None } else { node.prev };
+            }
+
+            (*last.as_ptr()).prev = before;
+            (*first.as_ptr()).next = after;
+
+            match before {
+                None => self.head = Some(last),
+                Some(before) => (*before.as_ptr()).next = Some(last),
+            }
+
+            match after {
+                None => self.tail = Some(first),
+                Some(after) => (*after.as_ptr()).prev = Some(first),
+            }
+        }
+    }
+
+    /// Rotates the list `k` places to the left.
+    ///
+    /// The first `k` elements are moved to the back of the list. The new head is found from
+    /// whichever end of the list is closer, then only the boundary pointers are relinked.
+    ///
+    /// # Panics
+    /// Panics if `k` is greater than the length of the list.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// list.rotate_left(2);
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![3, 4, 5, 1, 2]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(min(k, len - k))**
+    pub fn rotate_left(&mut self, k: usize) {
+        assert!(k <= self.len, "cannot rotate by more than the length of the list");
+
+        if k == 0 || k == self.len {
+            return;
+        }
+
+        let (Some(head), Some(tail), Some(new_head)) = (self.head, self.tail, self.find_node(k))
+        else {
+            unreachable!("the list has at least two elements");
+        };
+
+        unsafe {
+            let new_tail = (*new_head.as_ptr()).prev.take();
+
+            if let Some(new_tail) = new_tail {
+                (*new_tail.as_ptr()).next = None;
+            }
+
+            (*tail.as_ptr()).next = Some(head);
+            (*head.as_ptr()).prev = Some(tail);
+
+            self.head = Some(new_head);
+            self.tail = new_tail;
+        }
+    }
+
+    /// Rotates the list `k` places to the right.
+    ///
+    /// The last `k` elements are moved to the front of the list, see
+    /// [`LinkedList::rotate_left`].
+    ///
+    /// # Panics
+    /// Panics if `k` is greater than the length of the list.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// list.rotate_right(2);
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![4, 5, 1, 2, 3]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(min(k, len - k))**
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len, "cannot rotate by more than the length of the list");
+        self.rotate_left(self.len - k);
+    }
+
+    /// Sorts the list in ascending order.
+    ///
+    /// The sort is stable and is performed in place by relinking the nodes, the elements
+    /// are never moved and no memory is allocated.
+    ///
+    /// If the implementation of [`Ord`] for `T` panics, the list is left as a valid
+    /// permutation of its original elements.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([3, 1, 2]);
+    /// list.sort();
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n log n)** comparisons and **O(1)** extra memory.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.merge_sort(|a, b| a < b);
+    }
+
+    /// Sorts the list with a comparator function.
+    ///
+    /// The sort is stable and is performed in place by relinking the nodes. If `compare`
+    /// panics, the list is left as a valid permutation of its original elements.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 3, 2]);
+    /// list.sort_by(|a, b| b.cmp(a));
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n log n)** comparisons and **O(1)** extra memory.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.merge_sort(|a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Sorts the list with a key extraction function.
+    ///
+    /// The key is computed on every comparison, see [`LinkedList::sort_by_cached_key`]
+    /// for keys that are expensive to compute.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([-3i32, 1, -2]);
+    /// list.sort_by_key(|x| x.abs());
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, -2, -3]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n log n)** comparisons and **O(1)** extra memory.
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.merge_sort(|a, b| f(a) < f(b));
+    }
+
+    /// Sorts the list with a key extraction function, computing each key only once.
+    ///
+    /// The keys are stored in a temporary buffer together with pointers to their nodes,
+    /// the buffer is sorted and the nodes are then relinked in the resulting order.
+    /// If `f` or the [`Ord`] implementation of `K` panics, the list is left untouched.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from(["ccc", "a", "bb"]);
+    /// list.sort_by_cached_key(|s| s.len());
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec!["a", "bb", "ccc"]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n log n)** comparisons, **O(n)** key computations and **O(n)** extra memory.
+    pub fn sort_by_cached_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        if self.len < 2 {
+            return;
+        }
+
+        let mut keyed = Vec::with_capacity(self.len);
+        let mut current = self.head;
+
+        while let Some(node) = current {
+            unsafe {
+                keyed.push((f(&node.as_ref().element), node));
+                current = node.as_ref().next;
+            }
+        }
+
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut prev: Link<T> = None;
+
+        for &(_, node) in &keyed {
+            unsafe {
+                (*node.as_ptr()).prev = prev;
+                match prev {
+                    None => self.head = Some(node),
+                    Some(prev) => (*prev.as_ptr()).next = Some(node),
+                }
+            }
+            prev = Some(node);
+        }
+
+        if let Some(tail) = prev {
+            unsafe { (*tail.as_ptr()).next = None };
+        }
+
+        self.tail = prev;
+    }
+
+    /// Checks if the elements of the list are sorted in ascending order.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// assert!(LinkedList::from([1, 2, 2, 3]).is_sorted());
+    /// assert!(!LinkedList::from([2, 1]).is_sorted());
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n)**
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Checks if the elements of the list are sorted using the given comparator function.
+    ///
+    /// `compare` receives every pair of adjacent elements and must return `true` if they
+    /// are in order.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// assert!(LinkedList::from([3, 2, 1]).is_sorted_by(|a, b| a >= b));
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n)**
+    pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut iter = self.iter();
+
+        let Some(mut last) = iter.next() else {
+            return true;
+        };
+
+        iter.all(|current| {
+            let ordered = compare(last, current);
+            last = current;
+            ordered
+        })
+    }
+
+    /// Merges the sorted list `other` into this sorted list.
+    ///
+    /// The nodes of both lists are interleaved by relinking them, no element is moved or
+    /// reallocated. The merge is stable: equal elements of `self` come before the equal
+    /// elements of `other`. After this operation `other` is empty.
+    ///
+    /// If the implementation of [`Ord`] for `T` panics, every element of both lists ends up
+    /// in `self` in an unspecified order and `other` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut first = LinkedList::from([1, 3, 5]);
+    /// let mut second = LinkedList::from([2, 3, 4]);
+    ///
+    /// first.merge(&mut second);
+    ///
+    /// assert_eq!(first.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 3, 4, 5]);
+    /// assert!(second.is_empty());
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n + m)**, where *n* and *m* are the lengths of the lists.
+    pub fn merge(&mut self, other: &mut LinkedList<T>)
+    where
+        T: Ord,
+    {
+        self.merge_with(other, |a, b| a < b);
+    }
+
+    /// Merges the list `other`, sorted by `compare`, into this list sorted by `compare`.
+    ///
+    /// Works like [`LinkedList::merge`] with a custom comparator function.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut first = LinkedList::from([5, 3, 1]);
+    /// let mut second = LinkedList::from([4, 2]);
+    ///
+    /// first.merge_by(&mut second, |a, b| b.cmp(a));
+    ///
+    /// assert_eq!(first.into_iter().collect::<Vec<_>>(), vec![5, 4, 3, 2, 1]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n + m)**, where *n* and *m* are the lengths of the lists.
+    pub fn merge_by<F>(&mut self, other: &mut LinkedList<T>, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.merge_with(other, |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Merges any number of sorted lists into a single sorted list.
+    ///
+    /// The lists are merged pairwise in rounds, so every node is relinked **O(log k)** times.
+    /// The merge is stable: equal elements keep the order of the lists they came from.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let lists = vec![
+    ///     LinkedList::from([1, 4, 7]),
+    ///     LinkedList::from([2, 5, 8]),
+    ///     LinkedList::from([3, 6, 9]),
+    /// ];
+    ///
+    /// let merged = LinkedList::merge_all(lists);
+    ///
+    /// assert_eq!(merged.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n log k)**, where *n* is the total number of elements and *k* the number of lists.
+    pub fn merge_all<I>(lists: I) -> LinkedList<T>
+    where
+        I: IntoIterator<Item = LinkedList<T>>,
+        T: Ord,
+    {
+        let mut lists = lists
+            .into_iter()
+            .filter(|list| !list.is_empty())
+            .collect::<Vec<_>>();
+
+        while lists.len() > 1 {
+            let mut merged = Vec::with_capacity(lists.len().div_ceil(2));
+            let mut pairs = lists.into_iter();
+
+            while let Some(mut first) = pairs.next() {
+                if let Some(mut second) = pairs.next() {
+                    first.merge(&mut second);
+                }
+                merged.push(first);
+            }
+
+            lists = merged;
+        }
+
+        lists.pop().unwrap_or_default()
+    }
+
+    /// Returns the union of two sorted lists as a sorted list.
+    ///
+    /// The nodes of both lists are reused: every element present in either list is relinked
+    /// into the result once. When both lists contain an equal element, the one from `self`
+    /// is kept and the one from `other` is dropped. Each element of one list matches at most
+    /// one equal element of the other, so duplicates inside a list are preserved.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let first = LinkedList::from([1, 2, 4]);
+    /// let second = LinkedList::from([2, 3, 4, 5]);
+    ///
+    /// let union = first.union(second);
+    ///
+    /// assert_eq!(union.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n + m)**, where *n* and *m* are the lengths of the lists.
+    pub fn union(self, other: LinkedList<T>) -> LinkedList<T>
+    where
+        T: Ord,
+    {
+        self.union_by(other, T::cmp)
+    }
+
+    /// Returns the union of two lists sorted by `compare`.
+    ///
+    /// Works like [`LinkedList::union`] with a custom comparator function.
+    ///
+    /// # Complexity
+    /// - **O(n + m)**, where *n* and *m* are the lengths of the lists.
+    pub fn union_by<F>(self, other: LinkedList<T>, compare: F) -> LinkedList<T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.merge_sets(other, compare, SetOperation::Union)
+    }
+
+    /// Returns the intersection of two sorted lists as a sorted list.
+    ///
+    /// The elements of `self` that have an equal element in `other` are relinked into the
+    /// result, every other element of both lists is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let first = LinkedList::from([1, 2, 4]);
+    /// let second = LinkedList::from([2, 3, 4, 5]);
+    ///
+    /// let intersection = first.intersection(second);
+    ///
+    /// assert_eq!(intersection.into_iter().collect::<Vec<_>>(), vec![2, 4]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n + m)**, where *n* and *m* are the lengths of the lists.
+    pub fn intersection(self, other: LinkedList<T>) -> LinkedList<T>
+    where
+        T: Ord,
+    {
+        self.intersection_by(other, T::cmp)
+    }
+
+    /// Returns the intersection of two lists sorted by `compare`.
+    ///
+    /// Works like [`LinkedList::intersection`] with a custom comparator function.
+    ///
+    /// # Complexity
+    /// - **O(n + m)**, where *n* and *m* are the lengths of the lists.
+    pub fn intersection_by<F>(self, other: LinkedList<T>, compare: F) -> LinkedList<T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.merge_sets(other, compare, SetOperation::Intersection)
+    }
+
+    /// Returns the elements of the sorted list `self` that are not in the sorted list `other`.
+    ///
+    /// The surviving elements of `self` are relinked into the result, every element of
+    /// `other` and every matched element of `self` is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let first = LinkedList::from([1, 2, 4]);
+    /// let second = LinkedList::from([2, 3, 4, 5]);
+    ///
+    /// let difference = first.difference(second);
+    ///
+    /// assert_eq!(difference.into_iter().collect::<Vec<_>>(), vec![1]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n + m)**, where *n* and *m* are the lengths of the lists.
+    pub fn difference(self, other: LinkedList<T>) -> LinkedList<T>
+    where
+        T: Ord,
+    {
+        self.difference_by(other, T::cmp)
+    }
+
+    /// Returns the difference of two lists sorted by `compare`.
+    ///
+    /// Works like [`LinkedList::difference`] with a custom comparator function.
+    ///
+    /// # Complexity
+    /// - **O(n + m)**, where *n* and *m* are the lengths of the lists.
+    pub fn difference_by<F>(self, other: LinkedList<T>, compare: F) -> LinkedList<T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.merge_sets(other, compare, SetOperation::Difference)
+    }
+
+    /// Returns the elements that are in exactly one of two sorted lists as a sorted list.
+    ///
+    /// The unmatched elements of both lists are relinked into the result, every matched pair
+    /// of equal elements is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let first = LinkedList::from([1, 2, 4]);
+    /// let second = LinkedList::from([2, 3, 4, 5]);
+    ///
+    /// let symmetric_difference = first.symmetric_difference(second);
+    ///
+    /// assert_eq!(symmetric_difference.into_iter().collect::<Vec<_>>(), vec![1, 3, 5]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - **O(n + m)**, where *n* and *m* are the lengths of the lists.
+    pub fn symmetric_difference(self, other: LinkedList<T>) -> LinkedList<T>
+    where
+        T: Ord,
+    {
+        self.symmetric_difference_by(other, T::cmp)
+    }
+
+    /// Returns the symmetric difference of two lists sorted by `compare`.
+    ///
+    /// Works like [`LinkedList::symmetric_difference`] with a custom comparator function.
+    ///
+    /// # Complexity
+    /// - **O(n + m)**, where *n* and *m* are the lengths of the lists.
+    pub fn symmetric_difference_by<F>(self, other: LinkedList<T>, compare: F) -> LinkedList<T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.merge_sets(other, compare, SetOperation::SymmetricDifference)
+    }
+
+    /// Walks two sorted lists in lockstep and relinks the nodes selected by `operation`
+    /// into a new list.
+    ///
+    /// Every node that is not kept is freed. If `compare` panics, the three lists are
+    /// dropped normally, so no node is leaked.
+    fn merge_sets<F>(
+        mut self,
+        mut other: LinkedList<T>,
+        mut compare: F,
+        operation: SetOperation,
+    ) -> LinkedList<T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (keep_left, keep_right, keep_both) = match operation {
+            SetOperation::Union => (true, true, true),
+            SetOperation::Intersection => (false, false, true),
+            SetOperation::Difference => (true, false, false),
+            SetOperation::SymmetricDifference => (true, true, false),
+        };
+
+        let mut result = LinkedList::new();
+
+        while let (Some(left), Some(right)) = (self.front(), other.front()) {
+            match compare(left, right) {
+                Ordering::Less => {
+                    let node = self.pop_front_node();
+                    if let (true, Some(node)) = (keep_left, node) {
+                        result.push_back_node(node);
+                    }
+                }
+                Ordering::Greater => {
+                    let node = other.pop_front_node();
+                    if let (true, Some(node)) = (keep_right, node) {
+                        result.push_back_node(node);
+                    }
+                }
+                Ordering::Equal => {
+                    let node = self.pop_front_node();
+                    drop(other.pop_front_node());
+                    if let (true, Some(node)) = (keep_both, node) {
+                        result.push_back_node(node);
+                    }
+                }
+            }
+        }
+
+        if keep_left {
+            result.append(&mut self);
+        }
+
+        if keep_right {
+            result.append(&mut other);
+        }
+
+        result
+    }
+
+    /// Merges `other` into the list, both sorted according to `is_less`.
+    fn merge_with<F>(&mut self, other: &mut LinkedList<T>, mut is_less: F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        if other.is_empty() {
+            return;
+        }
+
+        if self.is_empty() {
+            std::mem::swap(self, other);
+            return;
+        }
+
+        let left = self.head.take();
+        let right = other.head.take();
+
+        self.tail = None;
+        other.tail = None;
+        self.len += std::mem::replace(&mut other.len, 0);
+
+        let mut guard = MergeGuard::new(self);
+        guard.merged = guard.merge(left, right, &mut is_less);
+    }
+
+    /// Sorts the list with a stable bottom-up merge sort that only relinks the nodes.
+    ///
+    /// The nodes are taken from the front one by one and merged into bins, where the
+    /// bin `i` holds a sorted run of `2^i` nodes. All runs are kept in a [`MergeGuard`]
+    /// so the list can be rebuilt if `is_less` panics.
+    fn merge_sort<F>(&mut self, mut is_less: F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        if self.len < 2 {
+            return;
+        }
+
+        let input = self.head.take();
+        self.tail = None;
+
+        let mut guard = MergeGuard::new(self);
+        guard.input = input;
+
+        while let Some(node) = guard.input {
+            let mut carry = unsafe {
+                guard.input = (*node.as_ptr()).next.take();
+                Some(node)
+            };
+
+            let mut i = 0;
+            while let Some(bin) = guard.bins[i].take() {
+                carry = guard.merge(Some(bin), carry, &mut is_less);
+                i += 1;
+            }
+
+            guard.bins[i] = carry;
+        }
+
+        let mut result = None;
+        for i in 0..guard.bins.len() {
+            if let Some(bin) = guard.bins[i].take() {
+                result = guard.merge(Some(bin), result, &mut is_less);
+            }
+        }
+
+        guard.merged = result;
+    }
+
+    /// Unlinks the given node from the list and returns ownership of it.
+    ///
+    /// The neighbours of the node, `head`, `tail` and `len` are updated accordingly.
+    ///
+    /// # Safety
+    /// `node` must point to a node owned by this list.
+    unsafe fn unlink_node(&mut self, node: NonNull<Node<T>>) -> Box<Node<T>> {
+        let mut node = Box::from_raw(node.as_ptr());
+        let prev = node.prev.take();
+        let next = node.next.take();
+
+        match prev {
+            // the node is the head
+            None => self.head = next,
+            Some(prev) => (*prev.as_ptr()).next = next,
+        }
+
+        match next {
+            // the node is the tail
+            None => self.tail = prev,
+            Some(next) => (*next.as_ptr()).prev = prev,
+        }
+
+        self.len -= 1;
+        node
+    }
+
+    /// Links an unlinked node at the given index, `index` must be at most `len`.
+    fn insert_node(&mut self, index: usize, node: Box<Node<T>>) {
+        debug_assert!(index <= self.len);
+
+        let next = self.find_node(index);
+        let prev = match next {
+            None => self.tail,
+            Some(next) => unsafe { next.as_ref().prev },
+        };
+
+        unsafe { self.link_node(prev, next, node) };
+    }
+
+    /// Links an unlinked node between the adjacent nodes `prev` and `next`.
+    ///
+    /// `prev` being `None` means the node becomes the new head, `next` being `None` means
+    /// it becomes the new tail.
+    ///
+    /// # Safety
+    /// `prev` and `next` must be adjacent nodes owned by this list, or `None` at the
+    /// corresponding end of the list.
+    unsafe fn link_node(&mut self, prev: Link<T>, next: Link<T>, mut node: Box<Node<T>>) {
+        node.prev = prev;
+        node.next = next;
+
+        let node = NonNull::from(Box::leak(node));
+
+        match prev {
+            None => self.head = Some(node),
+            Some(prev) => (*prev.as_ptr()).next = Some(node),
+        }
+
+        match next {
+            None => self.tail = Some(node),
+            Some(next) => (*next.as_ptr()).prev = Some(node),
+        }
+
+        self.len += 1;
+    }
+
+    /// Returns a pointer to the node at the given index.
+    ///
+    /// The node is found by walking from whichever end of the list is closer to `index`.
+    /// Returns `None` if `index >= len`.
+    fn find_node(&self, index: usize) -> Link<T> {
+        if index >= self.len {
+            return None;
+        }
+
+        unsafe {
+            if index <= (self.len - 1) / 2 {
+                let mut current = self.head;
+                for _ in 0..index {
+                    current = current.and_then(|node| node.as_ref().next);
+                }
+                current
+            } else {
+                let mut current = self.tail;
+                for _ in index + 1..self.len {
+                    current = current.and_then(|node| node.as_ref().prev);
+                }
+                current
+            }
+        }
+    }
+
+    /// Detaches every node after `split_node` into a new list.
+    ///
+    /// `at` must be the number of nodes up to and including `split_node`. If `split_node`
+    /// is `None` the whole list is detached.
+    ///
+    /// # Safety
+    /// `split_node` must be `None` or point to a node owned by this list.
+    unsafe fn split_off_after_node(&mut self, split_node: Link<T>, at: usize) -> LinkedList<T> {
+        match split_node {
+            None => std::mem::take(self),
+            Some(split_node) => {
+                let second_head = (*split_node.as_ptr()).next.take();
+
+                let second = LinkedList {
+                    head: second_head,
+                    tail: if second_head.is_some() { self.tail } else { None },
+                    len: self.len - at,
+                    _marker: PhantomData,
+                };
+
+                if let Some(second_head) = second_head {
+                    (*second_head.as_ptr()).prev = None;
+                }
+
+                self.tail = Some(split_node);
+                self.len = at;
+
+                second
+            }
+        }
+    }
+
+    /// Detaches the nodes in the range `[start, end)` into a new list.
+    ///
+    /// Both boundary nodes are located from the nearer end of the list, the surrounding
+    /// nodes are then linked to each other directly.
+    fn detach_range(&mut self, start: usize, end: usize) -> LinkedList<T> {
+        self.detach_range_with_neighbours(start, end).0
+    }
+
+    /// Detaches the nodes in the range `[start, end)` into a new list, and returns it together
+    /// with the nodes that were before and after the range.
+    ///
+    /// The returned nodes are adjacent in the list once the range is detached, so they can be
+    /// passed to [`LinkedList::splice_nodes`] directly.
+    fn detach_range_with_neighbours(
+        &mut self,
+        start: usize,
+        end: usize,
+    ) -> (LinkedList<T>, Link<T>, Link<T>) {
+        debug_assert!(start <= end && end <= self.len);
+
+        if start == end {
+            let next = self.find_node(start);
+            let prev = match next {
+                None => self.tail,
+                Some(next) => unsafe { next.as_ref().prev },
+            };
+
+            return (LinkedList::new(), prev, next);
+        }
+
+        let (Some(first), Some(last)) = (self.find_node(start), self.find_node(end - 1)) else {
+            unreachable!("the range is within the bounds of the list");
+        };
+
+        let (prev, next) = unsafe {
+            let prev = (*first.as_ptr()).prev.take();
+            let next = (*last.as_ptr()).next.take();
+
+            match prev {
+                None => self.head = next,
+                Some(prev) => (*prev.as_ptr()).next = next,
+            }
+
+            match next {
+                None => self.tail = prev,
+                Some(next) => (*next.as_ptr()).prev = prev,
+            }
+
+            (prev, next)
+        };
+
+        self.len -= end - start;
+
+        let detached = LinkedList {
+            head: Some(first),
+            tail: Some(last),
+            len: end - start,
+            _marker: PhantomData,
+        };
+
+        (detached, prev, next)
+    }
+
+    /// Links all nodes of `other` between the adjacent nodes `prev` and `next`.
+    ///
+    /// `prev` being `None` means the nodes become the new head, `next` being `None` means
+    /// they become the new tail.
+    ///
+    /// # Safety
+    /// `prev` and `next` must be adjacent nodes owned by this list, or `None` at the
+    /// corresponding end of the list.
+    unsafe fn splice_nodes(&mut self, prev: Link<T>, next: Link<T>, mut other: LinkedList<T>) {
+        let (Some(other_head), Some(other_tail)) = (other.head.take(), other.tail.take()) else {
+            return;
+        };
+
+        (*other_head.as_ptr()).prev = prev;
+        (*other_tail.as_ptr()).next = next;
+
+        match prev {
+            None => self.head = Some(other_head),
+            Some(prev) => (*prev.as_ptr()).next = Some(other_head),
+        }
+
+        match next {
+            None => self.tail = Some(other_tail),
+            Some(next) => (*next.as_ptr()).prev = Some(other_tail),
+        }
+
+        self.len += std::mem::replace(&mut other.len, 0);
+    }
+}
+
+/// The set operation performed by `LinkedList::merge_sets`.
+///
+/// # Variants
+/// - `Union`: Keeps the elements of both lists, equal elements only once.
+/// - `Intersection`: Keeps the elements of `self` that have an equal element in `other`.
+/// - `Difference`: Keeps the elements of `self` that have no equal element in `other`.
+/// - `SymmetricDifference`: Keeps the elements of either list that have no equal element
+///   in the other one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SetOperation {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+/// The state of an in-progress merge sort or merge of sorted lists.
+///
+/// Every node of the list is always reachable from exactly one of the `None`-terminated
+/// chains held by the guard, linked through `next` only. When the guard is dropped, either
+/// after the operation completes or while unwinding from a panicking comparator, the chains are
+/// concatenated and the `prev` pointers, `head` and `tail` of the list are rebuilt.
+///
+/// # Fields
+/// - `list`: The list receiving the nodes, its `head` and `tail` are rebuilt on drop.
+/// - `input`: The nodes that have not been taken into a bin yet.
+/// - `bins`: Sorted runs, the bin `i` holds `2^i` nodes or is empty.
+/// - `left`: The remaining nodes of the left run of the current merge.
+/// - `right`: The remaining nodes of the right run of the current merge.
+/// - `merged`: The output of the current merge, or the whole result once the operation completes.
+struct MergeGuard<'a, T> {
+    list: &'a mut LinkedList<T>,
+    input: Link<T>,
+    bins: [Link<T>; usize::BITS as usize],
+    left: Link<T>,
+    right: Link<T>,
+    merged: Link<T>,
+}
+
+impl<'a, T> MergeGuard<'a, T> {
+    /// Creates a guard with no nodes for the given list.
+    ///
+    /// The caller must move the nodes of the list into the chains of the guard and keep
+    /// `list.len` equal to the total number of nodes held by the guard.
+    fn new(list: &'a mut LinkedList<T>) -> Self {
+        Self {
+            list,
+            input: None,
+            bins: [None; usize::BITS as usize],
+            left: None,
+            right: None,
+            merged: None,
+        }
+    }
+
+    /// Merges two sorted chains, taking from `left` on ties to keep the merge stable.
+    fn merge<F>(&mut self, left: Link<T>, right: Link<T>, is_less: &mut F) -> Link<T>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.left = left;
+        self.right = right;
+
+        let mut tail: Link<T> = None;
+
+        while let (Some(l), Some(r)) = (self.left, self.right) {
+            let node = unsafe {
+                if is_less(&r.as_ref().element, &l.as_ref().element) {
+                    self.right = (*r.as_ptr()).next.take();
+                    r
+                } else {
+                    self.left = (*l.as_ptr()).next.take();
+                    l
+                }
+            };
+
+            match tail {
+                None => self.merged = Some(node),
+                Some(tail) => unsafe { (*tail.as_ptr()).next = Some(node) },
+            }
+
+            tail = Some(node);
+        }
+
+        let rest = self.left.take().or(self.right.take());
+
+        match tail {
+            None => rest,
+            Some(tail) => unsafe {
+                (*tail.as_ptr()).next = rest;
+                self.merged.take()
+            },
+        }
+    }
+}
+
+impl<T> Drop for MergeGuard<'_, T> {
+    fn drop(&mut self) {
+        let chains = [self.merged, self.left, self.right]
+            .into_iter()
+            .chain(self.bins)
+            .chain([self.input]);
+
+        let mut head: Link<T> = None;
+        let mut tail: Link<T> = None;
+
+        for chain in chains {
+            let mut current = chain;
+
+            while let Some(node) = current {
+                unsafe {
+                    (*node.as_ptr()).prev = tail;
+                    match tail {
+                        None => head = Some(node),
+                        Some(tail) => (*tail.as_ptr()).next = Some(node),
+                    }
+                    current = node.as_ref().next;
+                }
+                tail = Some(node);
+            }
+        }
+
+        self.list.head = head;
+        self.list.tail = tail;
+    }
+}
+
+/// Converts a range into `(start, end)` indices of a list with the given length.
+///
+/// # Panics
+/// Panics if the start of the range is greater than its end or if the end is out of bounds.
+fn resolve_range<R>(range: R, len: usize) -> (usize, usize)
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .expect("attempted to index list from after maximum usize"),
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .expect("attempted to index list up to maximum usize"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(start <= end, "range start index {start} should be <= range end index {end}");
+    assert!(end <= len, "range end index {end} out of range for list of length {len}");
+
+    (start, end)
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "index {} is out of bounds for a list of length {}", self.index, self.len)
+    }
+}
+
+impl Error for IndexError {}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.index {
+            Some(index) => write!(f, "cannot move a cursor at index {index} by {}", self.offset)?,
+            None => write!(f, "cannot move a cursor at the ghost position by {}", self.offset)?,
+        }
+        write!(f, " in a list of length {}", self.len)
+    }
+}
+
+impl Error for MoveError {}
+
+impl<T> fmt::Display for InsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            InsertErrorKind::OutOfBounds { index, len } => {
+                write!(f, "insertion index {index} is out of bounds for a list of length {len}")
+            }
+            InsertErrorKind::AllocFailed => write!(f, "memory allocation for a node failed"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> Error for InsertError<T> {}
+
+impl<T> From<AllocError<T>> for InsertError<T> {
+    fn from(err: AllocError<T>) -> Self {
+        InsertError {
+            element: err.element,
+            kind: InsertErrorKind::AllocFailed,
+        }
+    }
+}
+
+impl<T> fmt::Display for AllocError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "memory allocation for a node failed")
+    }
+}
+
+impl<T: fmt::Debug> Error for AllocError<T> {}
+
+impl<E: fmt::Display> fmt::Display for ParseListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseListError::MissingBrackets => {
+                write!(f, "the list must be enclosed in `[` and `]`")
+            }
+            ParseListError::Element { index, error } => {
+                write!(f, "failed to parse the element at index {index}: {error}")
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseListError::MissingBrackets => None,
+            ParseListError::Element { error, .. } => Some(error),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for DisplayWith<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.open)?;
+
+        for (index, element) in self.list.iter().enumerate() {
+            if index > 0 {
+                f.write_str(self.separator)?;
+            }
+            fmt::Display::fmt(element, f)?;
+        }
+
+        f.write_str(self.close)
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    #[inline]
+    fn default() -> Self {
+        LinkedList::new()
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    /// Returns a deep copy of the list.
+    ///
+    /// If `T::clone` panics, the nodes cloned so far are dropped with the partially built list.
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+
+    /// Overwrites the list with a copy of `source`, reusing the nodes it already has.
+    ///
+    /// The existing elements are overwritten with [`Clone::clone_from`], surplus nodes are
+    /// freed and missing ones are allocated.
+    fn clone_from(&mut self, source: &Self) {
+        let mut source_iter = source.iter();
+
+        if self.len > source.len {
+            self.split_off(source.len);
+        }
+
+        for (element, source_element) in self.iter_mut().zip(&mut source_iter) {
+            element.clone_from(source_element);
+        }
+
+        for source_element in source_iter {
+            self.push_back(source_element.clone());
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for LinkedList<T> {
+    /// Formats the list as `[1, 2, 3]`, the format accepted by the [`FromStr`] implementation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display_with(", ", "[", "]"), f)
+    }
+}
+
+impl<T: FromStr> FromStr for LinkedList<T> {
+    type Err = ParseListError<T::Err>;
+
+    /// Parses a list written as `[1, 2, 3]`.
+    ///
+    /// The elements are separated by commas and parsed with `T::from_str` after trimming
+    /// the surrounding whitespace. Elements cannot contain commas themselves.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::{LinkedList, ParseListError};
+    ///
+    /// let list = "[1, 2, 3]".parse::<LinkedList<i32>>().unwrap();
+    /// assert_eq!(list, [1, 2, 3]);
+    ///
+    /// let err = "[1, x, 3]".parse::<LinkedList<i32>>().unwrap_err();
+    /// assert!(matches!(err, ParseListError::Element { index: 1, .. }));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .trim()
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or(ParseListError::MissingBrackets)?
+            .trim();
+
+        if inner.is_empty() {
+            return Ok(LinkedList::new());
+        }
+
+        inner
+            .split(',')
+            .enumerate()
+            .map(|(index, element)| {
+                element
+                    .trim()
+                    .parse()
+                    .map_err(|error| ParseListError::Element { index, error })
+            })
+            .collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for LinkedList<T> {
+    /// Feeds the length of the list followed by every element into the hasher,
+    /// the same way `std::collections::LinkedList` does.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for element in self.iter() {
+            element.hash(state);
+        }
+    }
+}
+
+// The list owns its elements, so it can be sent or shared exactly when `T` can.
+unsafe impl<T: Send> Send for LinkedList<T> {}
+
+unsafe impl<T: Sync> Sync for LinkedList<T> {}
+
+// `Iter` and `Cursor` only hand out `&T`, so they behave like `&LinkedList<T>`.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+unsafe impl<T: Sync> Send for Cursor<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Cursor<'_, T> {}
+
+// `IterMut`, `CursorMut` and `ExtractIf` hand out `&mut T`, so they behave like `&mut LinkedList<T>`.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+unsafe impl<T: Send> Send for CursorMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for CursorMut<'_, T> {}
+
+unsafe impl<T: Send, F: Send> Send for ExtractIf<'_, T, F> where F: FnMut(&mut T) -> bool {}
+
+unsafe impl<T: Sync, F: Sync> Sync for ExtractIf<'_, T, F> where F: FnMut(&mut T) -> bool {}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    /// Drops the elements from front to back.
+    ///
+    /// If the destructor of an element panics, a guard keeps freeing the remaining nodes
+    /// while unwinding, so no node is leaked.
+    fn drop(&mut self) {
+        struct DropGuard<'a, T>(&'a mut LinkedList<T>);
+
+        impl<T> Drop for DropGuard<'_, T> {
+            fn drop(&mut self) {
+                while self.0.pop_front_node().is_some() {}
+            }
+        }
+
+        let guard = DropGuard(self);
+        while let Some(node) = guard.0.pop_front_node() {
+            drop(node);
+        }
+        std::mem::forget(guard);
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Appends the elements of an iterator to the back of the list.
+///
+/// Every element is moved into a newly allocated node. To splice in the nodes of whole
+/// lists in **O(1)**, extend with the lists themselves, see the `Extend<LinkedList<T>>`
+/// implementation.
+///
+/// # Example
+/// ```
+/// use linked_list::LinkedList;
+///
+/// let mut list = LinkedList::from([1, 2]);
+/// list.extend(vec![3, 4]);
+///
+/// assert_eq!(list, [1, 2, 3, 4]);
+/// ```
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+/// Appends whole lists to the back of the list.
+///
+/// Every list is spliced in with [`LinkedList::append`], so each one costs **O(1)**
+/// regardless of its length.
+///
+/// # Example
+/// ```
+/// use linked_list::LinkedList;
+///
+/// let mut list = LinkedList::from([1, 2]);
+/// list.extend([LinkedList::from([3, 4]), LinkedList::from([5])]);
+///
+/// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+/// ```
+impl<T> Extend<LinkedList<T>> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = LinkedList<T>>>(&mut self, iter: I) {
+        for mut list in iter {
+            self.append(&mut list);
+        }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    fn from(array: [T; N]) -> Self {
+        Self::from_iter(array)
+    }
+}
+
+impl<T> From<Vec<T>> for LinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Self::from_iter(vec)
+    }
+}
+
+impl<T> From<VecDeque<T>> for LinkedList<T> {
+    fn from(deque: VecDeque<T>) -> Self {
+        Self::from_iter(deque)
+    }
+}
+
+impl<T> From<Box<[T]>> for LinkedList<T> {
+    fn from(slice: Box<[T]>) -> Self {
+        Self::from_iter(slice.into_vec())
+    }
+}
+
+impl<T> From<std::collections::LinkedList<T>> for LinkedList<T> {
+    fn from(list: std::collections::LinkedList<T>) -> Self {
+        Self::from_iter(list)
+    }
+}
+
+impl<T> From<LinkedList<T>> for Vec<T> {
+    /// Moves the elements of the list into a vector allocated with the exact length of the list.
+    fn from(list: LinkedList<T>) -> Self {
+        let mut vec = Vec::with_capacity(list.len);
+        vec.extend(list);
+        vec
+    }
+}
+
+impl<T> From<LinkedList<T>> for VecDeque<T> {
+    /// Moves the elements of the list into a deque allocated with the exact length of the list.
+    fn from(list: LinkedList<T>) -> Self {
+        let mut deque = VecDeque::with_capacity(list.len);
+        deque.extend(list);
+        deque
+    }
+}
+
+impl<T> From<LinkedList<T>> for Box<[T]> {
+    fn from(list: LinkedList<T>) -> Self {
+        Vec::from(list).into_boxed_slice()
+    }
+}
+
+impl<T> From<LinkedList<T>> for std::collections::LinkedList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T, U> PartialEq<[U]> for LinkedList<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.len == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<T, U> PartialEq<&[U]> for LinkedList<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &&[U]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T, U> PartialEq<Vec<U>> for LinkedList<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        *self == **other
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<[U; N]> for LinkedList<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<E> FromIterator<E> for LinkedList<E> {
+    fn from_iter<T: IntoIterator<Item = E>>(iter: T) -> Self {
+        let mut list = Self::default();
+        list.extend(iter);
+        list
+    }
+}
+
+/// Skips `n` nodes from the front of an iteration range and returns the node after them.
+///
+/// The node is found by walking from whichever end of the range is closer. If `n` is out of
+/// the range, the range is exhausted and `None` is returned.
+fn nth_node<T>(head: &mut Link<T>, tail: Link<T>, len: &mut usize, n: usize) -> Link<T> {
+    if n >= *len {
+        *len = 0;
+        return None;
+    }
+
+    let node = unsafe {
+        if n <= *len / 2 {
+            let mut current = *head;
+            for _ in 0..n {
+                current = current.and_then(|node| node.as_ref().next);
+            }
+            current
+        } else {
+            let mut current = tail;
+            for _ in n + 1..*len {
+                current = current.and_then(|node| node.as_ref().prev);
+            }
+            current
+        }
+    };
+
+    *head = node.and_then(|node| unsafe { node.as_ref().next });
+    *len -= n + 1;
+    node
+}
+
+/// Skips `n` nodes from the back of an iteration range and returns the node before them.
+///
+/// The node is found by walking from whichever end of the range is closer. If `n` is out of
+/// the range, the range is exhausted and `None` is returned.
+fn nth_back_node<T>(head: Link<T>, tail: &mut Link<T>, len: &mut usize, n: usize) -> Link<T> {
+    if n >= *len {
+        *len = 0;
+        return None;
+    }
+
+    let node = unsafe {
+        if n <= *len / 2 {
+            let mut current = *tail;
+            for _ in 0..n {
+                current = current.and_then(|node| node.as_ref().prev);
+            }
+            current
+        } else {
+            let mut current = head;
+            for _ in n + 1..*len {
+                current = current.and_then(|node| node.as_ref().next);
+            }
+            current
+        }
+    };
+
+    *tail = node.and_then(|node| unsafe { node.as_ref().prev });
+    *len -= n + 1;
+    node
+}
+
+/// Formats the remaining elements of an iterator as a list inside a tuple struct.
+struct DebugEntries<I>(I);
+
+impl<I> fmt::Debug for DebugEntries<I>
+where
+    I: Iterator + Clone,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.clone()).finish()
+    }
+}
+
+impl<'a, T: 'a> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            self.head.map(|node| unsafe {
+                let node = &*node.as_ptr();
+                self.head = node.next;
+                self.len -= 1;
+                &node.element
+            })
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        nth_node(&mut self.head, self.tail, &mut self.len, n)
+            .map(|node| unsafe { &(*node.as_ptr()).element })
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut accum = init;
+        let mut current = self.head;
+
+        for _ in 0..self.len {
+            let Some(node) = current else { break };
+            unsafe {
+                let node = &*node.as_ptr();
+                current = node.next;
+                accum = f(accum, &node.element);
+            }
+        }
+
+        accum
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            self.tail.map(|node| unsafe {
+                let node = &*node.as_ptr();
+                self.len -= 1;
+                self.tail = node.prev;
+                &node.element
+            })
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        nth_back_node(self.head, &mut self.tail, &mut self.len, n)
+            .map(|node| unsafe { &(*node.as_ptr()).element })
+    }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut accum = init;
+        let mut current = self.tail;
+
+        for _ in 0..self.len {
+            let Some(node) = current else { break };
+            unsafe {
+                let node = &*node.as_ptr();
+                current = node.prev;
+                accum = f(accum, &node.element);
+            }
+        }
+
+        accum
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter").field(&DebugEntries(self.clone())).finish()
+    }
+}
+
+impl<'a, T: 'a> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            self.head.map(|node| unsafe {
+                let node = &mut *node.as_ptr();
+                self.len -= 1;
+                self.head = node.next;
+                &mut node.element
+            })
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        nth_node(&mut self.head, self.tail, &mut self.len, n)
+            .map(|node| unsafe { &mut (*node.as_ptr()).element })
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut accum = init;
+        let mut current = self.head;
+
+        for _ in 0..self.len {
+            let Some(node) = current else { break };
+            unsafe {
+                let node = &mut *node.as_ptr();
+                current = node.next;
+                accum = f(accum, &mut node.element);
+            }
+        }
+
+        accum
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
             None
         } else {
-            self.head.map(|node| unsafe {
-                let node = &mut *node.as_ptr();
-                self.len -= 1;
-                self.head = node.next;
-                &mut node.element
-            })
+            self.tail.map(|node| unsafe {
+                let node = &mut *node.as_ptr();
+                self.len -= 1;
+                self.tail = node.prev;
+                &mut node.element
+            })
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        nth_back_node(self.head, &mut self.tail, &mut self.len, n)
+            .map(|node| unsafe { &mut (*node.as_ptr()).element })
+    }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut accum = init;
+        let mut current = self.tail;
+
+        for _ in 0..self.len {
+            let Some(node) = current else { break };
+            unsafe {
+                let node = &mut *node.as_ptr();
+                current = node.prev;
+                accum = f(accum, &mut node.element);
+            }
+        }
+
+        accum
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<T: fmt::Debug> fmt::Debug for IterMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let remaining = Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            _marker: PhantomData,
+        };
+
+        f.debug_tuple("IterMut").field(&DebugEntries(remaining)).finish()
+    }
+}
+
+/// Moves the cursor to the next element in the list.
+///
+/// The ghost position between the tail and the head is represented by `current` being `None`
+/// and `index` being the length of the list.
+fn move_next<T>(current: &mut Link<T>, index: &mut usize, list: &LinkedList<T>) {
+    match current {
+        None => {
+            *current = list.head;
+            *index = 0;
+        }
+        Some(node) => unsafe {
+            *current = node.as_ref().next;
+            *index += 1;
+        },
+    }
+}
+
+/// Moves the cursor to the previous element in the list.
+fn move_prev<T>(current: &mut Link<T>, index: &mut usize, list: &LinkedList<T>) {
+    match current {
+        None => {
+            *current = list.tail;
+            *index = list.len().saturating_sub(1)
+        }
+        Some(node) => unsafe {
+            *current = node.as_ref().prev;
+            *index = match current {
+                None => list.len(),
+                Some(_) => *index - 1,
+            }
+        },
+    }
+}
+
+/// Moves the cursor to the element at `target`, or returns an error if it is out of bounds.
+fn seek<T>(
+    current: &mut Link<T>,
+    index: &mut usize,
+    list: &LinkedList<T>,
+    target: usize,
+) -> Result<(), IndexError> {
+    if target >= list.len {
+        return Err(IndexError {
+            index: target,
+            len: list.len,
+        });
+    }
+
+    walk_to(current, index, list, target);
+    Ok(())
+}
+
+/// Moves the cursor by `offset` elements, or returns an error if it would leave the list.
+fn move_by<T>(
+    current: &mut Link<T>,
+    index: &mut usize,
+    list: &LinkedList<T>,
+    offset: isize,
+) -> Result<(), MoveError> {
+    if offset == 0 {
+        return Ok(());
+    }
+
+    match offset_index(*current, *index, list.len, offset, false) {
+        Some(target) => {
+            walk_to(current, index, list, target);
+            Ok(())
+        }
+        None => Err(MoveError {
+            offset,
+            index: current.map(|_| *index),
+            len: list.len,
+        }),
+    }
+}
+
+/// Moves the cursor by `offset` elements, stopping at the first or last element.
+fn move_by_clamped<T>(
+    current: &mut Link<T>,
+    index: &mut usize,
+    list: &LinkedList<T>,
+    offset: isize,
+) {
+    if offset == 0 {
+        return;
+    }
+
+    if let Some(target) = offset_index(*current, *index, list.len, offset, true) {
+        walk_to(current, index, list, target);
+    }
+}
+
+/// Moves the cursor to the head of the list, or to the ghost position if it is empty.
+fn move_to_front<T>(current: &mut Link<T>, index: &mut usize, list: &LinkedList<T>) {
+    *current = list.head;
+    *index = 0;
+}
+
+/// Moves the cursor to the tail of the list, or to the ghost position if it is empty.
+fn move_to_back<T>(current: &mut Link<T>, index: &mut usize, list: &LinkedList<T>) {
+    *current = list.tail;
+    *index = list.len.saturating_sub(1);
+}
+
+/// Moves the cursor to the ghost position.
+fn move_to_ghost<T>(current: &mut Link<T>, index: &mut usize, list: &LinkedList<T>) {
+    *current = None;
+    *index = list.len;
+}
+
+/// Moves the cursor to the element at `target`, which must be less than the length of the list.
+///
+/// The node is reached by walking from whichever of the current node, the head and the tail
+/// is the closest to `target`.
+fn walk_to<T>(current: &mut Link<T>, index: &mut usize, list: &LinkedList<T>, target: usize) {
+    debug_assert!(target < list.len);
+
+    let from_head = target;
+    let from_tail = list.len - 1 - target;
+    let from_current = current.map(|_| index.abs_diff(target));
+
+    let (mut node, mut at) = match from_current {
+        Some(steps) if steps <= from_head.min(from_tail) => (*current, *index),
+        _ if from_head <= from_tail => (list.head, 0),
+        _ => (list.tail, list.len - 1),
+    };
+
+    unsafe {
+        while at < target {
+            node = node.and_then(|node| node.as_ref().next);
+            at += 1;
+        }
+        while at > target {
+            node = node.and_then(|node| node.as_ref().prev);
+            at -= 1;
+        }
+    }
+
+    *current = node;
+    *index = target;
+}
+
+/// Returns the index of the element `offset` positions away from the cursor.
+///
+/// From the ghost position, a positive offset counts from the head and a negative one from the
+/// tail, like repeated calls to `move_next` or `move_prev` would. Returns `None` if the
+/// target is outside of the list. If `clamp` is set, the target is clamped to the first or
+/// last element instead, and `None` is only returned for an empty list.
+fn offset_index<T>(
+    current: Link<T>,
+    index: usize,
+    len: usize,
+    offset: isize,
+    clamp: bool,
+) -> Option<usize> {
+    debug_assert!(offset != 0);
+
+    let steps = offset.unsigned_abs();
+    let last = len.checked_sub(1)?;
+
+    let target = match (current, offset >= 0) {
+        (Some(_), true) => index.checked_add(steps),
+        (Some(_), false) => index.checked_sub(steps),
+        (None, true) => Some(steps - 1),
+        (None, false) => len.checked_sub(steps),
+    };
+
+    match target {
+        Some(target) if target <= last => Some(target),
+        _ if !clamp => None,
+        _ if offset >= 0 => Some(last),
+        _ => Some(0),
+    }
+}
+
+impl<'a, T: 'a> Cursor<'a, T> {
+    /// Returns the current index if the cursor is pointing to an element.
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Returns a reference to the current element, if any.
+    ///
+    /// The cursor only shares the list, so the reference may outlive the cursor itself.
+    #[inline]
+    pub fn current(&self) -> Option<&'a T> {
+        self.current
+            .map(|node| unsafe { &(*node.as_ptr()).element })
+    }
+
+    /// Moves the cursor to the next element in the list.
+    pub fn move_next(&mut self) {
+        move_next(&mut self.current, &mut self.index, self.list);
+    }
+
+    /// Moves the cursor to the previous element in the list.
+    pub fn move_prev(&mut self) {
+        move_prev(&mut self.current, &mut self.index, self.list);
+    }
+
+    /// Moves the cursor to the element at the given index.
+    ///
+    /// The element is reached from whichever of the current position, the front and the back
+    /// of the list is the closest. If `index` is out of bounds, the cursor is left unchanged.
+    ///
+    /// # Complexity
+    /// O(min(d, index, len - index)), where `d` is the distance from the current position.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::{IndexError, LinkedList};
+    ///
+    /// let list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_front();
+    ///
+    /// assert_eq!(cursor.seek(3), Ok(()));
+    /// assert_eq!(cursor.current(), Some(&4));
+    /// assert_eq!(cursor.seek(5), Err(IndexError { index: 5, len: 5 }));
+    /// assert_eq!(cursor.index(), Some(3));
+    /// ```
+    pub fn seek(&mut self, index: usize) -> Result<(), IndexError> {
+        seek(&mut self.current, &mut self.index, self.list, index)
+    }
+
+    /// Moves the cursor by `offset` elements, forwards if it is positive and backwards otherwise.
+    ///
+    /// From the ghost position the offset counts from the front or the back of the list.
+    /// If the move would go past either end of the list, the cursor is left unchanged and an
+    /// error is returned, see [`Cursor::move_by_clamped`] for a saturating variant.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_front();
+    ///
+    /// assert!(cursor.move_by(3).is_ok());
+    /// assert_eq!(cursor.current(), Some(&4));
+    /// assert!(cursor.move_by(-2).is_ok());
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// assert!(cursor.move_by(4).is_err());
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// ```
+    pub fn move_by(&mut self, offset: isize) -> Result<(), MoveError> {
+        move_by(&mut self.current, &mut self.index, self.list, offset)
+    }
+
+    /// Moves the cursor by `offset` elements, stopping at the first or last element.
+    ///
+    /// On an empty list the cursor stays at the ghost position.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front();
+    ///
+    /// cursor.move_by_clamped(10);
+    /// assert_eq!(cursor.current(), Some(&3));
+    /// cursor.move_by_clamped(-10);
+    /// assert_eq!(cursor.current(), Some(&1));
+    /// ```
+    pub fn move_by_clamped(&mut self, offset: isize) {
+        move_by_clamped(&mut self.current, &mut self.index, self.list, offset);
+    }
+
+    /// Moves the cursor to the first element of the list, or to the ghost position if it is empty.
+    #[inline]
+    pub fn move_to_front(&mut self) {
+        move_to_front(&mut self.current, &mut self.index, self.list);
+    }
+
+    /// Moves the cursor to the last element of the list, or to the ghost position if it is empty.
+    #[inline]
+    pub fn move_to_back(&mut self) {
+        move_to_back(&mut self.current, &mut self.index, self.list);
+    }
+
+    /// Moves the cursor to the ghost position between the back and the front of the list.
+    #[inline]
+    pub fn move_to_ghost(&mut self) {
+        move_to_ghost(&mut self.current, &mut self.index, self.list);
+    }
+
+    /// Peeks at the next element without moving the cursor.
+    pub fn peek_next(&self) -> Option<&'a T> {
+        unsafe {
+            let next = match self.current {
+                None => self.list.head,
+                Some(node) => node.as_ref().next,
+            };
+            next.map(|node| &(*node.as_ptr()).element)
+        }
+    }
+
+    /// Peeks at the previous element without moving the cursor.
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        unsafe {
+            let prev = match self.current {
+                None => self.list.tail,
+                Some(current) => current.as_ref().prev,
+            };
+            prev.map(|node| &(*node.as_ptr()).element)
+        }
+    }
+
+    /// Returns an iterator over the elements from the current one to the back of the list.
+    ///
+    /// The iterator is empty at the ghost position.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from([1, 2, 3, 4]);
+    /// let mut cursor = list.cursor_front();
+    /// cursor.move_next();
+    ///
+    /// let iter = cursor.iter_forward();
+    /// assert_eq!(iter.len(), 3);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![&2, &3, &4]);
+    /// ```
+    pub fn iter_forward(&self) -> Iter<'a, T> {
+        Iter {
+            head: self.current,
+            tail: self.current.and(self.list.tail),
+            len: self.current.map_or(0, |_| self.list.len - self.index),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the elements from the current one to the front of the list.
+    ///
+    /// The iterator is empty at the ghost position.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from([1, 2, 3, 4]);
+    /// let mut cursor = list.cursor_back();
+    /// cursor.move_prev();
+    ///
+    /// let iter = cursor.iter_backward();
+    /// assert_eq!(iter.len(), 3);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![&3, &2, &1]);
+    /// ```
+    pub fn iter_backward(&self) -> Rev<Iter<'a, T>> {
+        Iter {
+            head: self.current.and(self.list.head),
+            tail: self.current,
+            len: self.current.map_or(0, |_| self.index + 1),
+            _marker: PhantomData,
+        }
+        .rev()
+    }
+
+    /// Returns the number of elements from this cursor to `other`.
+    ///
+    /// The result is positive if `other` is after this cursor and negative if it is before.
+    /// The ghost position counts as the position after the last element.
+    ///
+    /// # Panics
+    /// Panics if the cursors point into different lists.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from([1, 2, 3, 4]);
+    /// let start = list.cursor_front();
+    /// let mut end = start;
+    /// end.move_by(3).unwrap();
+    ///
+    /// assert_eq!(start.distance_to(&end), 3);
+    /// assert_eq!(end.distance_to(&start), -3);
+    /// ```
+    pub fn distance_to(&self, other: &Cursor<'a, T>) -> isize {
+        self.assert_same_list(other);
+
+        if self.index <= other.index {
+            (other.index - self.index) as isize
+        } else {
+            -((self.index - other.index) as isize)
+        }
+    }
+
+    /// Returns an iterator over the elements from this cursor up to, but excluding, `end`.
+    ///
+    /// If `end` is at the ghost position, the iterator runs to the back of the list.
+    ///
+    /// # Panics
+    /// Panics if the cursors point into different lists, or if `end` is before this cursor.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let mut start = list.cursor_front();
+    /// start.move_next();
+    /// let mut end = start;
+    /// end.move_by(3).unwrap();
+    ///
+    /// let between = start.iter_until(&end);
+    /// assert_eq!(between.len(), 3);
+    /// assert_eq!(between.collect::<Vec<_>>(), vec![&2, &3, &4]);
+    /// ```
+    pub fn iter_until(&self, end: &Cursor<'a, T>) -> Iter<'a, T> {
+        self.assert_same_list(end);
+        assert!(
+            self.index <= end.index,
+            "cursor end index {} should be >= cursor start index {}",
+            end.index,
+            self.index
+        );
+
+        let len = end.index - self.index;
+        let tail = match end.current {
+            _ if len == 0 => None,
+            None => self.list.tail,
+            Some(node) => unsafe { node.as_ref().prev },
+        };
+
+        Iter {
+            head: if len == 0 { None } else { self.current },
+            tail,
+            len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Panics if `other` does not point into the same list as this cursor.
+    fn assert_same_list(&self, other: &Cursor<'a, T>) {
+        assert!(std::ptr::eq(self.list, other.list), "cursors point into different lists");
+    }
+
+    /// Returns a reference to the first element of the list the cursor points into.
+    #[inline]
+    pub fn front(&self) -> Option<&'a T> {
+        self.list.front()
+    }
+
+    /// Returns a reference to the last element of the list the cursor points into.
+    #[inline]
+    pub fn back(&self) -> Option<&'a T> {
+        self.list.back()
+    }
+}
+
+impl<T> Clone for Cursor<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Cursor<'_, T> {}
+
+impl<T: fmt::Debug> fmt::Debug for Cursor<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Cursor").field(self.list).field(&self.index()).finish()
+    }
+}
+
+/// Two cursors are equal if they point into the same list at the same position.
+impl<T> PartialEq for Cursor<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.list, other.list) && self.index == other.index
+    }
+}
+
+impl<T> Eq for Cursor<'_, T> {}
+
+/// Cursors into the same list are ordered by their position, the ghost position being after
+/// the last element. Cursors into different lists are not comparable.
+impl<T> PartialOrd for Cursor<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if !std::ptr::eq(self.list, other.list) {
+            return None;
+        }
+
+        Some(self.index.cmp(&other.index))
+    }
+}
+
+impl<'a, T: 'a> CursorMut<'a, T> {
+    /// Returns the current index if the cursor is pointing to an element.
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Returns a mutable reference to the current element, if any.
+    ///
+    /// The reference borrows the cursor, so the cursor cannot move or modify the list while
+    /// it is alive. Use [`CursorMut::into_current`] to keep the reference for the lifetime
+    /// of the list.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front_mut();
+    ///
+    /// if let Some(current) = cursor.current() {
+    ///     *current = 10;
+    /// }
+    /// cursor.move_next();
+    ///
+    /// assert_eq!(cursor.current(), Some(&mut 2));
+    /// assert_eq!(list, [10, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current
+            .map(|node| unsafe { &mut (*node.as_ptr()).element })
+    }
+
+    /// Consumes the cursor and returns a mutable reference to the current element, if any.
+    ///
+    /// Unlike [`CursorMut::current`], the returned reference lives as long as the borrow of
+    /// the list the cursor was created from.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    ///
+    /// let second = cursor.into_current().unwrap();
+    /// *second *= 10;
+    ///
+    /// assert_eq!(list, [1, 20, 3]);
+    /// ```
+    #[inline]
+    pub fn into_current(self) -> Option<&'a mut T> {
+        self.current
+            .map(|node| unsafe { &mut (*node.as_ptr()).element })
+    }
+
+    /// Moves the cursor to the next element in the list.
+    pub fn move_next(&mut self) {
+        move_next(&mut self.current, &mut self.index, self.list);
+    }
+
+    /// Moves the cursor to the previous element in the list.
+    pub fn move_prev(&mut self) {
+        move_prev(&mut self.current, &mut self.index, self.list);
+    }
+
+    /// Moves the cursor to the element at the given index.
+    ///
+    /// See [`Cursor::seek`].
+    pub fn seek(&mut self, index: usize) -> Result<(), IndexError> {
+        seek(&mut self.current, &mut self.index, self.list, index)
+    }
+
+    /// Moves the cursor by `offset` elements, forwards if it is positive and backwards otherwise.
+    ///
+    /// See [`Cursor::move_by`].
+    pub fn move_by(&mut self, offset: isize) -> Result<(), MoveError> {
+        move_by(&mut self.current, &mut self.index, self.list, offset)
+    }
+
+    /// Moves the cursor by `offset` elements, stopping at the first or last element.
+    ///
+    /// See [`Cursor::move_by_clamped`].
+    pub fn move_by_clamped(&mut self, offset: isize) {
+        move_by_clamped(&mut self.current, &mut self.index, self.list, offset);
+    }
+
+    /// Moves the cursor to the first element of the list, or to the ghost position if it is empty.
+    #[inline]
+    pub fn move_to_front(&mut self) {
+        move_to_front(&mut self.current, &mut self.index, self.list);
+    }
+
+    /// Moves the cursor to the last element of the list, or to the ghost position if it is empty.
+    #[inline]
+    pub fn move_to_back(&mut self) {
+        move_to_back(&mut self.current, &mut self.index, self.list);
+    }
+
+    /// Moves the cursor to the ghost position between the back and the front of the list.
+    #[inline]
+    pub fn move_to_ghost(&mut self) {
+        move_to_ghost(&mut self.current, &mut self.index, self.list);
+    }
+
+    /// Peeks at the next element without moving the cursor.
+    ///
+    /// The reference borrows the cursor, like the one returned by [`CursorMut::current`].
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        unsafe {
+            let next = match self.current {
+                None => self.list.head,
+                Some(current) => current.as_ref().next,
+            };
+
+            next.map(|node| &mut (*node.as_ptr()).element)
+        }
+    }
+
+    /// Peeks at the previous element without moving the cursor.
+    ///
+    /// The reference borrows the cursor, like the one returned by [`CursorMut::current`].
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        unsafe {
+            let prev = match self.current {
+                None => self.list.tail,
+                Some(current) => current.as_ref().prev,
+            };
+
+            prev.map(|node| &mut (*node.as_ptr()).element)
+        }
+    }
+
+    /// Returns a reference to the first element of the list the cursor points into.
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    /// Returns a mutable reference to the first element of the list the cursor points into.
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.list.front_mut()
+    }
+
+    /// Returns a reference to the last element of the list the cursor points into.
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.list.back()
+    }
+
+    /// Returns a mutable reference to the last element of the list the cursor points into.
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.list.back_mut()
+    }
+
+    /// Returns an iterator over mutable references to the elements from the current one to the
+    /// back of the list.
+    ///
+    /// The iterator borrows the cursor and is empty at the ghost position.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    ///
+    /// for element in cursor.iter_mut_forward() {
+    ///     *element *= 10;
+    /// }
+    /// assert_eq!(list, [1, 20, 30, 40]);
+    /// ```
+    pub fn iter_mut_forward(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.current,
+            tail: self.current.and(self.list.tail),
+            len: self.current.map_or(0, |_| self.list.len - self.index),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a read-only cursor at the same position.
+    ///
+    /// The `CursorMut` is borrowed for the lifetime of the returned cursor, so it cannot
+    /// modify the list in the meantime.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    ///
+    /// let view = cursor.as_cursor();
+    /// assert_eq!(view.current(), Some(&2));
+    /// assert_eq!(view.peek_prev(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        Cursor {
+            index: self.index,
+            current: self.current,
+            list: self.list,
+        }
+    }
+
+    /// Deletes the current element and moves the cursor to the next element.
+    ///
+    /// If the cursor is at the ghost position, the list is left unchanged and `None` is returned.
+    pub fn delete(&mut self) -> Option<T> {
+        self.unlink_current().map(|node| node.element)
+    }
+
+    /// Removes the current element and returns it as a list of its own.
+    ///
+    /// The node is moved into the returned list without reallocating. The cursor moves to
+    /// the next element, like with [`CursorMut::delete`].
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front_mut();
+    ///
+    /// let removed = cursor.remove_current_as_list().unwrap();
+    /// assert_eq!(removed, [1]);
+    /// assert_eq!(cursor.current(), Some(&mut 2));
+    /// assert_eq!(list, [2, 3]);
+    /// ```
+    pub fn remove_current_as_list(&mut self) -> Option<LinkedList<T>> {
+        self.unlink_current().map(|node| {
+            let mut list = LinkedList::new();
+            list.push_back_node(node);
+            list
+        })
+    }
+
+    /// Unlinks the current node and moves the cursor to the next one.
+    fn unlink_current(&mut self) -> Option<Box<Node<T>>> {
+        self.current.map(|node| unsafe {
+            self.current = node.as_ref().next;
+            self.list.unlink_node(node)
+        })
+    }
+
+    /// Splits the list in two after the current element.
+    ///
+    /// Returns a new list with everything after the cursor, the original list keeps the
+    /// current element and everything before it. At the ghost position the whole content
+    /// of the list is moved to the returned list.
+    ///
+    /// # Complexity
+    /// O(1).
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    ///
+    /// let tail = cursor.split_after();
+    /// assert_eq!(tail, [3, 4]);
+    /// assert_eq!(list, [1, 2]);
+    /// ```
+    pub fn split_after(&mut self) -> LinkedList<T> {
+        let at = match self.current {
+            None => 0,
+            Some(_) => self.index + 1,
+        };
+
+        let split = unsafe { self.list.split_off_after_node(self.current, at) };
+        if self.current.is_none() {
+            self.index = 0;
+        }
+        split
+    }
+
+    /// Splits the list in two before the current element.
+    ///
+    /// Returns a new list with everything before the cursor, the original list keeps the
+    /// current element and everything after it. At the ghost position the whole content
+    /// of the list is moved to the returned list.
+    ///
+    /// # Complexity
+    /// O(1).
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    ///
+    /// let head = cursor.split_before();
+    /// assert_eq!(cursor.index(), Some(0));
+    /// assert_eq!(head, [1]);
+    /// assert_eq!(list, [2, 3, 4]);
+    /// ```
+    pub fn split_before(&mut self) -> LinkedList<T> {
+        let prev = match self.current {
+            None => self.list.tail,
+            Some(current) => unsafe { current.as_ref().prev },
+        };
+
+        // `self.index` is the number of nodes before the cursor, also at the ghost position.
+        let mut split = unsafe { self.list.split_off_after_node(prev, self.index) };
+        std::mem::swap(self.list, &mut split);
+        self.index = 0;
+        split
+    }
+
+    /// Inserts the elements of `list` after the current element.
+    ///
+    /// At the ghost position the elements are inserted at the front of the list. The nodes
+    /// of `list` are relinked without reallocating.
+    ///
+    /// # Complexity
+    /// O(1).
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 4]);
+    /// let mut cursor = list.cursor_front_mut();
+    ///
+    /// cursor.splice_after(LinkedList::from([2, 3]));
+    /// assert_eq!(cursor.current(), Some(&mut 1));
+    /// assert_eq!(list, [1, 2, 3, 4]);
+    /// ```
+    pub fn splice_after(&mut self, list: LinkedList<T>) {
+        unsafe {
+            match self.current {
+                None => {
+                    self.index += list.len;
+                    let head = self.list.head;
+                    self.list.splice_nodes(None, head, list);
+                }
+                Some(current) => {
+                    let next = current.as_ref().next;
+                    self.list.splice_nodes(Some(current), next, list);
+                }
+            }
+        }
+    }
+
+    /// Inserts the elements of `list` before the current element.
+    ///
+    /// At the ghost position the elements are inserted at the back of the list. The nodes
+    /// of `list` are relinked without reallocating.
+    ///
+    /// # Complexity
+    /// O(1).
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 4]);
+    /// let mut cursor = list.cursor_back_mut();
+    ///
+    /// cursor.splice_before(LinkedList::from([2, 3]));
+    /// assert_eq!(cursor.index(), Some(3));
+    /// assert_eq!(list, [1, 2, 3, 4]);
+    /// ```
+    pub fn splice_before(&mut self, list: LinkedList<T>) {
+        self.index += list.len;
+
+        unsafe {
+            let prev = match self.current {
+                None => self.list.tail,
+                Some(current) => current.as_ref().prev,
+            };
+            self.list.splice_nodes(prev, self.current, list);
+        }
+    }
+
+    /// Prepends an element to the list, the cursor keeps pointing to the same element.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([2, 3]);
+    /// let mut cursor = list.cursor_back_mut();
+    ///
+    /// cursor.push_front(1);
+    /// assert_eq!(cursor.index(), Some(2));
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    pub fn push_front(&mut self, element: T) {
+        self.list.push_front(element);
+        self.index += 1;
+    }
+
+    /// Appends an element to the list, the cursor keeps pointing to the same element.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2]);
+    /// let mut cursor = list.cursor_back_mut();
+    ///
+    /// cursor.push_back(3);
+    /// assert_eq!(cursor.current(), Some(&mut 2));
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    pub fn push_back(&mut self, element: T) {
+        self.list.push_back(element);
+        if self.current.is_none() {
+            self.index += 1;
+        }
+    }
+
+    /// Removes the first element of the list and returns it, if any.
+    ///
+    /// If the cursor points to the first element, it moves to the next element.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front_mut();
+    ///
+    /// assert_eq!(cursor.pop_front(), Some(1));
+    /// assert_eq!(cursor.current(), Some(&mut 2));
+    /// assert_eq!(cursor.index(), Some(0));
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.list.head?;
+
+        if self.current == Some(head) {
+            self.current = unsafe { head.as_ref().next };
+        } else {
+            self.index -= 1;
+        }
+
+        self.list.pop_front()
+    }
+
+    /// Removes the last element of the list and returns it, if any.
+    ///
+    /// If the cursor points to the last element, it moves to the ghost position.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_back_mut();
+    ///
+    /// assert_eq!(cursor.pop_back(), Some(3));
+    /// assert_eq!(cursor.current(), None);
+    /// assert_eq!(cursor.peek_prev(), Some(&mut 2));
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.list.tail?;
+
+        match self.current {
+            None => self.index -= 1,
+            // the tail index is the new length, which is also the ghost index
+            Some(current) if current == tail => self.current = None,
+            Some(_) => {}
+        }
+
+        self.list.pop_back()
+    }
+
+    /// Inserts an element before the current position.
+    ///
+    /// At the ghost position the element is appended to the back of the list.
+    pub fn insert_before(&mut self, element: T) {
+        self.insert_node_before(Node::boxed(element));
+    }
+
+    /// Inserts an element before the current position, returning it back if the allocation fails.
+    pub fn try_insert_before(&mut self, element: T) -> Result<(), AllocError<T>> {
+        let node = Node::try_boxed(element).map_err(|element| AllocError { element })?;
+        self.insert_node_before(node);
+        Ok(())
+    }
+
+    /// Inserts an element after the current position.
+    ///
+    /// At the ghost position the element is prepended to the front of the list.
+    pub fn insert_after(&mut self, element: T) {
+        self.insert_node_after(Node::boxed(element));
+    }
+
+    /// Inserts an element after the current position, returning it back if the allocation fails.
+    pub fn try_insert_after(&mut self, element: T) -> Result<(), AllocError<T>> {
+        let node = Node::try_boxed(element).map_err(|element| AllocError { element })?;
+        self.insert_node_after(node);
+        Ok(())
+    }
+
+    /// Links an unlinked node before the current position.
+    fn insert_node_before(&mut self, node: Box<Node<T>>) {
+        unsafe {
+            let prev = match self.current {
+                None => self.list.tail,
+                Some(current) => current.as_ref().prev,
+            };
+            self.list.link_node(prev, self.current, node);
+        }
+        self.index += 1;
+    }
+
+    /// Links an unlinked node after the current position.
+    fn insert_node_after(&mut self, node: Box<Node<T>>) {
+        match self.current {
+            None => {
+                self.list.push_front_node(node);
+                self.index += 1;
+            }
+            Some(current) => unsafe {
+                let next = current.as_ref().next;
+                self.list.link_node(Some(current), next, node);
+            },
+        }
+    }
+}
+
+impl<T> IntoIter<T> {
+    /// Returns a reference to the list of the elements that have not been yielded yet.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut iter = LinkedList::from([1, 2, 3]).into_iter();
+    /// iter.next();
+    ///
+    /// assert_eq!(iter.as_list().len(), 2);
+    /// assert_eq!(iter.as_list().front(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn as_list(&self) -> &LinkedList<T> {
+        &self.list
+    }
+
+    /// Consumes the iterator and returns the elements that have not been yielded yet as a list.
+    ///
+    /// The remaining nodes are handed over as they are, nothing is reallocated.
+    ///
+    /// # Example
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut iter = LinkedList::from([1, 2, 3, 4]).into_iter();
+    /// iter.next();
+    /// iter.next_back();
+    ///
+    /// let rest = iter.into_list();
+    /// assert_eq!(rest.into_iter().collect::<Vec<_>>(), vec![2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_list(self) -> LinkedList<T> {
+        self.list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.list).finish()
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.list.len
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.list.len {
+            self.list.clear();
+            return None;
+        }
+
+        drop(self.list.detach_range(0, n));
+        self.list.pop_front()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let len = self.list.len;
+        if n >= len {
+            self.list.clear();
+            return None;
+        }
+
+        drop(self.list.detach_range(len - n, len));
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> FusedIterator for Drain<'_, T> {}
+
+impl<T, F> Iterator for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.current {
+            unsafe {
+                self.current = node.as_ref().next;
+                self.remaining -= 1;
+
+                if (self.pred)(&mut (*node.as_ptr()).element) {
+                    return Some(self.list.unlink_node(node).element);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+/// Creates a [`LinkedList`] containing the arguments.
+///
+/// `list!` allows lists to be defined with the same syntax as array expressions:
+/// - A list containing the given elements: `list![1, 2, 3]`.
+/// - A list containing `n` clones of an element: `list![value; n]`.
+/// - An empty list: `list![]`.
+///
+/// # Example
+/// ```
+/// use linked_list::{list, LinkedList};
+///
+/// let list = list![1, 2, 3];
+/// assert_eq!(list, [1, 2, 3]);
+///
+/// let repeated = list![String::from("a"); 2];
+/// assert_eq!(repeated, ["a", "a"]);
+///
+/// let empty: LinkedList<i32> = list![];
+/// assert!(empty.is_empty());
+/// ```
+#[macro_export]
+macro_rules! list {
+    () => {
+        $crate::LinkedList::new()
+    };
+    ($elem:expr; $n:expr) => {
+        $crate::from_elem($elem, $n)
+    };
+    ($($x:expr),+ $(,)?) => {
+        <$crate::LinkedList<_> as ::core::convert::From<_>>::from([$($x),+])
+    };
+}
+
+/// Creates a list of `n` elements for `list![elem; n]`.
+///
+/// Like `vec!`, the element is cloned `n - 1` times and moved into the last node, or dropped
+/// if `n` is zero.
+#[doc(hidden)]
+pub fn from_elem<T: Clone>(elem: T, n: usize) -> LinkedList<T> {
+    let mut list = LinkedList::new();
+
+    if let Some(clones) = n.checked_sub(1) {
+        list.extend(std::iter::repeat_with(|| elem.clone()).take(clones));
+        list.push_back(elem);
+    }
+
+    list
+}
+
+/// Compile-fail checks for the thread safety, variance and drop check of the list types.
+///
+/// A list of non-`Send` elements cannot be sent to another thread:
+/// ```compile_fail
+/// use linked_list::LinkedList;
+/// use std::rc::Rc;
+///
+/// fn assert_send<S: Send>(_: S) {}
+///
+/// assert_send(LinkedList::from([Rc::new(1)]));
+/// ```
+///
+/// A list of non-`Sync` elements cannot be shared between threads:
+/// ```compile_fail
+/// use linked_list::LinkedList;
+/// use std::cell::Cell;
+///
+/// fn assert_sync<S: Sync>(_: &S) {}
+///
+/// assert_sync(&LinkedList::from([Cell::new(1)]));
+/// ```
+///
+/// `Iter` and `Cursor` share the elements, so they require `T: Sync` to be sent:
+/// ```compile_fail
+/// use linked_list::LinkedList;
+/// use std::cell::Cell;
+///
+/// fn assert_send<S: Send>(_: S) {}
+///
+/// let list = LinkedList::from([Cell::new(1)]);
+/// assert_send(list.iter());
+/// ```
+///
+/// ```compile_fail
+/// use linked_list::LinkedList;
+/// use std::cell::Cell;
+///
+/// fn assert_send<S: Send>(_: S) {}
+///
+/// let list = LinkedList::from([Cell::new(1)]);
+/// assert_send(list.cursor_front());
+/// ```
+///
+/// `IterMut`, `IntoIter` and `CursorMut` give access to owned or mutable elements,
+/// so they require `T: Send` to be sent:
+/// ```compile_fail
+/// use linked_list::LinkedList;
+/// use std::rc::Rc;
+///
+/// fn assert_send<S: Send>(_: S) {}
+///
+/// let mut list = LinkedList::from([Rc::new(1)]);
+/// assert_send(list.iter_mut());
+/// ```
+///
+/// ```compile_fail
+/// use linked_list::LinkedList;
+/// use std::rc::Rc;
+///
+/// fn assert_send<S: Send>(_: S) {}
+///
+/// assert_send(LinkedList::from([Rc::new(1)]).into_iter());
+/// ```
+///
+/// ```compile_fail
+/// use linked_list::LinkedList;
+/// use std::rc::Rc;
+///
+/// fn assert_send<S: Send>(_: S) {}
+///
+/// let mut list = LinkedList::from([Rc::new(1)]);
+/// assert_send(list.cursor_front_mut());
+/// ```
+///
+/// `IterMut` is invariant in `T`, a longer-lived element type cannot be shortened through it:
+/// ```compile_fail
+/// use linked_list::IterMut;
+///
+/// fn shorten<'a, 'b>(iter: IterMut<'a, &'static str>) -> IterMut<'a, &'b str> {
+///     iter
+/// }
+/// ```
+///
+/// The list owns its elements, so an element borrowing data that is dropped before the list
+/// is rejected by the drop check:
+/// ```compile_fail
+/// use linked_list::LinkedList;
+///
+/// struct PrintOnDrop<'a>(&'a String);
+///
+/// impl Drop for PrintOnDrop<'_> {
+///     fn drop(&mut self) {
+///         println!("{}", self.0);
+///     }
+/// }
+///
+/// let mut list = LinkedList::new();
+/// let value = String::from("value");
+/// list.push_back(PrintOnDrop(&value));
+/// ```
+///
+/// A mutable reference handed out by `CursorMut` borrows the cursor, so two references to
+/// the same element cannot be alive at once:
+/// ```compile_fail
+/// use linked_list::LinkedList;
+///
+/// let mut list = LinkedList::from([1, 2, 3]);
+/// let mut cursor = list.cursor_front_mut();
+///
+/// let first = cursor.current().unwrap();
+/// let second = cursor.current().unwrap();
+/// *first += *second;
+/// ```
+///
+/// ```compile_fail
+/// use linked_list::LinkedList;
+///
+/// let mut list = LinkedList::from([1, 2, 3]);
+/// let mut cursor = list.cursor_front_mut();
+///
+/// let next = cursor.peek_next().unwrap();
+/// cursor.move_next();
+/// let current = cursor.current().unwrap();
+/// *next += *current;
+/// ```
+///
+/// Nor can a reference be kept across a removal of the element it points to:
+/// ```compile_fail
+/// use linked_list::LinkedList;
+///
+/// let mut list = LinkedList::from([1, 2, 3]);
+/// let mut cursor = list.cursor_front_mut();
+///
+/// let first = cursor.current().unwrap();
+/// cursor.delete();
+/// *first += 1;
+/// ```
+///
+/// ```compile_fail
+/// use linked_list::LinkedList;
+///
+/// let mut list = LinkedList::from([1, 2, 3]);
+/// let mut cursor = list.cursor_front_mut();
+///
+/// let prev = {
+///     cursor.move_next();
+///     cursor.peek_prev().unwrap()
+/// };
+/// cursor.move_prev();
+/// cursor.delete();
+/// *prev += 1;
+/// ```
+///
+/// The reference returned by `into_current` keeps the list borrowed:
+/// ```compile_fail
+/// use linked_list::LinkedList;
+///
+/// let mut list = LinkedList::from([1, 2, 3]);
+/// let first = list.cursor_front_mut().into_current().unwrap();
+///
+/// list.clear();
+/// *first += 1;
+/// ```
+#[allow(dead_code)]
+mod compile_fail {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_push_front() {
+        let mut list = LinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn test_push_back() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn test_front() {
+        let mut list = LinkedList::new();
+
+        list.push_front(3);
+        assert_eq!(list.front(), Some(&3));
+
+        list.push_front(2);
+        assert_eq!(list.front(), Some(&2));
+
+        list.push_front(1);
+        assert_eq!(list.front(), Some(&1));
+    }
+
+    #[test]
+    fn test_back() {
+        let mut list = LinkedList::new();
+
+        list.push_back(1);
+        assert_eq!(list.back(), Some(&1));
+
+        list.push_back(2);
+        assert_eq!(list.back(), Some(&2));
+
+        list.push_back(3);
+        assert_eq!(list.back(), Some(&3));
+    }
+
+    #[test]
+    fn test_front_mut() {
+        let mut list = LinkedList::new();
+
+        list.push_front(3);
+        assert_eq!(list.front_mut(), Some(&mut 3));
+
+        if let Some(element) = list.front_mut() {
+            *element = 2;
+        }
+
+        assert_eq!(list.pop_front(), Some(2));
+    }
+
+    #[test]
+    fn test_back_mut() {
+        let mut list = LinkedList::new();
+
+        list.push_back(1);
+        assert_eq!(list.back_mut(), Some(&mut 1));
+
+        if let Some(elem) = list.back_mut() {
+            *elem = 2;
+        }
+
+        assert_eq!(list.pop_back(), Some(2));
+    }
+
+    #[test]
+    fn test_iter() {
+        let list = LinkedList::from([1, 2, 3, 4, 5]);
+
+        let mut iter = list.iter();
+
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+
+        let mut iter = list.iter_mut();
+
+        assert_eq!(iter.next(), Some(&mut 1));
+        assert_eq!(iter.next(), Some(&mut 2));
+        assert_eq!(iter.next_back(), Some(&mut 5));
+        assert_eq!(iter.next_back(), Some(&mut 4));
+        assert_eq!(iter.next(), Some(&mut 3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_len() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        list.pop_back();
+        assert_eq!(list.len(), 2);
+        list.pop_front();
+        assert_eq!(list.len(), 1);
+        list.pop_front();
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut list = LinkedList::new();
+        assert!(list.is_empty());
+        list.push_back(1);
+        assert!(!list.is_empty());
+        list.pop_back();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let elements = [1, 2, 3];
+        let mut list = LinkedList::from_iter(elements.iter());
+
+        assert_eq!(list.pop_front(), Some(&1));
+        assert_eq!(list.pop_front(), Some(&2));
+        assert_eq!(list.pop_front(), Some(&3));
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let list = LinkedList::from([1, 2, 3, 4, 5]);
+        let mut iter = list.into_iter();
+
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_cursor_move_next() {
+        let list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_front();
+
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(cursor.index(), Some(0));
+
+        cursor.move_next();
+
+        assert_eq!(cursor.current(), Some(&2));
+        assert_eq!(cursor.index(), Some(1));
+
+        cursor.move_next();
+
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(2));
+
+        cursor.move_next();
+
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+    }
+
+    #[test]
+    fn test_cursor_move_back() {
+        let list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_back();
+
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(2));
+
+        cursor.move_prev();
+
+        assert_eq!(cursor.current(), Some(&2));
+        assert_eq!(cursor.index(), Some(1));
+
+        cursor.move_prev();
+
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(cursor.index(), Some(0));
+
+        cursor.move_prev();
+
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+    }
+
+    #[test]
+    fn test_cursor_peek_next() {
+        let list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_front();
+
+        assert_eq!(cursor.peek_next(), Some(&2));
+
+        cursor.move_next();
+
+        assert_eq!(cursor.peek_next(), Some(&3));
+
+        cursor.move_next();
+
+        assert_eq!(cursor.peek_next(), None);
+    }
+
+    #[test]
+    fn test_cursor_peek_prev() {
+        let list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_back();
+
+        assert_eq!(cursor.peek_prev(), Some(&2));
+
+        cursor.move_prev();
+
+        assert_eq!(cursor.peek_prev(), Some(&1));
+
+        cursor.move_prev();
+
+        assert_eq!(cursor.peek_prev(), None);
+    }
+
+    #[test]
+    fn test_cursor_mut_move_next() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_front_mut();
+
+        assert_eq!(cursor.current(), Some(&mut 1));
+        assert_eq!(cursor.index(), Some(0));
+
+        cursor.move_next();
+
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(cursor.index(), Some(1));
+
+        cursor.move_next();
+
+        assert_eq!(cursor.current(), Some(&mut 3));
+        assert_eq!(cursor.index(), Some(2));
+
+        cursor.move_next();
+
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+    }
+
+    #[test]
+    fn test_cursor_mut_move_back() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_back_mut();
+
+        assert_eq!(cursor.current(), Some(&mut 3));
+        assert_eq!(cursor.index(), Some(2));
+
+        cursor.move_prev();
+
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(cursor.index(), Some(1));
+
+        cursor.move_prev();
+
+        assert_eq!(cursor.current(), Some(&mut 1));
+        assert_eq!(cursor.index(), Some(0));
+
+        cursor.move_prev();
+
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+    }
+
+    #[test]
+    fn test_cursor_mut_peek_next() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_front_mut();
+
+        assert_eq!(cursor.peek_next(), Some(&mut 2));
+
+        cursor.move_next();
+
+        assert_eq!(cursor.peek_next(), Some(&mut 3));
+
+        cursor.move_next();
+
+        assert_eq!(cursor.peek_next(), None);
+    }
+
+    #[test]
+    fn test_cursor_mut_peek_prev() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_back_mut();
+
+        assert_eq!(cursor.peek_prev(), Some(&mut 2));
+
+        cursor.move_prev();
+
+        assert_eq!(cursor.peek_prev(), Some(&mut 1));
+
+        cursor.move_prev();
+
+        assert_eq!(cursor.peek_prev(), None);
+    }
+
+    #[test]
+    fn test_cursor_mut_delete_head() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_front_mut();
+
+        let deleted = cursor.delete();
+
+        assert_eq!(deleted, Some(1));
+
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(cursor.index(), Some(0));
+
+        let values = list.into_iter().collect::<Vec<_>>();
+
+        assert_eq!(values, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_cursor_mut_delete_mid() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.move_next();
+
+        let deleted = cursor.delete();
+        assert_eq!(deleted, Some(2));
+
+        assert_eq!(cursor.current(), Some(&mut 3));
+        assert_eq!(cursor.index(), Some(1));
+
+        let values = list.into_iter().collect::<Vec<_>>();
+
+        assert_eq!(values, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_cursor_mut_delete_tail() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_back_mut();
+
+        let deleted = cursor.delete();
+        assert_eq!(deleted, Some(3));
+
+        assert_eq!(cursor.current(), None);
+
+        let values = list.into_iter().collect::<Vec<_>>();
+
+        assert_eq!(values, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_cursor_mut_insert_before_when_current_empty() {
+        let mut list = LinkedList::from([2, 3]);
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.insert_before(1);
+
+        let values = list.into_iter().collect::<Vec<_>>();
+
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_cursor_mut_insert_after_when_prev_empty() {
+        let mut list = LinkedList::from([2, 3]);
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.insert_before(1);
+
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(cursor.index(), Some(1));
+
+        let values = list.into_iter().collect::<Vec<_>>();
+
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_cursor_mut_insert_before() {
+        let mut list = LinkedList::from([1, 3]);
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.move_next();
+
+        cursor.insert_before(2);
+
+        assert_eq!(cursor.current(), Some(&mut 3));
+        assert_eq!(cursor.index(), Some(2));
+
+        let values = list.into_iter().collect::<Vec<_>>();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_cursor_mut_insert_after_when_current_empty() {
+        let mut list = LinkedList::from([1, 2]);
+        let mut cursor = list.cursor_back_mut();
+
+        cursor.insert_after(3);
+
+        let values = list.into_iter().collect::<Vec<i32>>();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_cursor_mut_insert_after_when_next_empty() {
+        let mut list = LinkedList::from([1, 2]);
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.move_next();
+
+        cursor.insert_after(3);
+
+        let values = list.into_iter().collect::<Vec<i32>>();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_cursor_mut_insert_after() {
+        let mut list = LinkedList::from([1, 3]);
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.insert_after(2);
+
+        let values = list.into_iter().collect::<Vec<i32>>();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+    
+    #[test]
+    fn test_cursor_mut_insert_before_when_list_is_empty() {
+        let mut list = LinkedList::new();
+        let mut cursor = list.cursor_front_mut();
+        
+        cursor.insert_before(1);
+        let values = list.into_iter().collect::<Vec<i32>>();
+        assert_eq!(values, vec![1]);
+    }
+
+    #[test]
+    fn test_cursor_mut_insert_after_when_list_is_empty() {
+        let mut list = LinkedList::new();
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.insert_after(1);
+        let values = list.into_iter().collect::<Vec<i32>>();
+        assert_eq!(values, vec![1]);
+    }
+    
+    #[test]
+    fn test_cursor_mut_delete_when_list_has_only_one_element() {
+        let mut list = LinkedList::from([1]);
+        let mut cursor = list.cursor_front_mut();
+        
+        cursor.delete();
+        
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_cursor_move_prev_to_ghost() {
+        let list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_front();
+
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index, 3);
+
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(2));
+
+        let empty = LinkedList::<i32>::new();
+        let mut cursor = empty.cursor_front();
+
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.index, 0);
+    }
+
+    #[test]
+    fn test_cursor_mut_insert_at_ghost() {
+        let mut list = LinkedList::from([2]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        cursor.insert_before(3);
+        cursor.insert_after(1);
+        assert_eq!(cursor.index, 3);
+        assert_eq!(cursor.peek_next(), Some(&mut 1));
+        assert_eq!(cursor.peek_prev(), Some(&mut 3));
+
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(list, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_cursor_mut_front_back() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        *cursor.front_mut().unwrap() = 10;
+        *cursor.back_mut().unwrap() = 30;
+        assert_eq!(cursor.front(), Some(&10));
+        assert_eq!(cursor.back(), Some(&30));
+        assert_eq!(cursor.as_cursor().front(), Some(&10));
+        assert_eq!(cursor.as_cursor().back(), Some(&30));
+        assert_eq!(cursor.as_cursor().index(), Some(1));
+    }
+
+    #[test]
+    fn test_cursor_mut_remove_current_as_list() {
+        let mut list = LinkedList::from([1, 2]);
+        let mut cursor = list.cursor_back_mut();
+
+        assert_eq!(cursor.remove_current_as_list(), Some(LinkedList::from([2])));
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.remove_current_as_list(), None);
+        assert_eq!(cursor.index, 1);
+        assert_eq!(list, [1]);
+    }
+
+    #[test]
+    fn test_cursor_mut_split() {
+        let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+
+        assert_eq!(cursor.split_before(), [1, 2]);
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.split_after(), [4, 5]);
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.current(), Some(&mut 3));
+
+        cursor.move_next();
+        cursor.push_back(4);
+        assert_eq!(cursor.index, 2);
+        assert_eq!(cursor.split_before(), [3, 4]);
+        assert_eq!(cursor.index, 0);
+
+        let mut list = LinkedList::from([1, 2]);
+        let mut cursor = list.cursor_back_mut();
+        cursor.move_next();
+
+        assert_eq!(cursor.split_after(), [1, 2]);
+        assert_eq!(cursor.index, 0);
+        assert!(list.is_empty());
+
+        let mut list = LinkedList::from([1, 2]);
+        let mut cursor = list.cursor_front_mut();
+
+        assert!(cursor.split_before().is_empty());
+        assert_eq!(cursor.split_after(), [2]);
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn test_cursor_mut_splice() {
+        let mut list = LinkedList::from([3]);
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.splice_before(LinkedList::from([1, 2]));
+        cursor.splice_after(LinkedList::from([4]));
+        cursor.splice_after(LinkedList::new());
+        assert_eq!(cursor.index(), Some(2));
+
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+
+        cursor.splice_after(LinkedList::from([-1, 0]));
+        cursor.splice_before(LinkedList::from([5, 6]));
+        assert_eq!(cursor.index, 8);
+
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut -1));
+        assert_eq!(list, [-1, 0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_cursor_mut_push_pop() {
+        let mut list = LinkedList::from([2, 3]);
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.push_front(1);
+        cursor.push_back(4);
+        assert_eq!(cursor.index(), Some(1));
+
+        assert_eq!(cursor.pop_front(), Some(1));
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.pop_front(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 3));
+        assert_eq!(cursor.index(), Some(0));
+
+        assert_eq!(cursor.pop_back(), Some(4));
+        assert_eq!(cursor.pop_back(), Some(3));
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index, 0);
+        assert_eq!(cursor.pop_back(), None);
+        assert_eq!(cursor.pop_front(), None);
+
+        cursor.push_back(1);
+        cursor.push_front(0);
+        assert_eq!(cursor.index, 2);
+        assert_eq!(cursor.pop_back(), Some(1));
+        assert_eq!(cursor.pop_front(), Some(0));
+        assert_eq!(cursor.index, 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_cursor_seek() {
+        let list = LinkedList::from_iter(0..7);
+
+        for start in 0..=7 {
+            for target in 0..7 {
+                let mut cursor = list.cursor_front();
+                cursor.move_by_clamped(start);
+                if start == 7 {
+                    cursor.move_to_ghost();
+                }
+
+                assert_eq!(cursor.seek(target), Ok(()));
+                assert_eq!(cursor.index(), Some(target));
+                assert_eq!(cursor.current(), Some(&target));
+            }
+        }
+
+        let mut cursor = list.cursor_back();
+        assert_eq!(cursor.seek(7), Err(IndexError { index: 7, len: 7 }));
+        assert_eq!(cursor.index(), Some(6));
+
+        let empty = LinkedList::<i32>::new();
+        let mut cursor = empty.cursor_front();
+        assert_eq!(cursor.seek(0), Err(IndexError { index: 0, len: 0 }));
+    }
+
+    #[test]
+    fn test_cursor_move_by() {
+        let list = LinkedList::from([0, 1, 2, 3, 4]);
+        let mut cursor = list.cursor_front();
+
+        assert_eq!(cursor.move_by(0), Ok(()));
+        assert_eq!(cursor.move_by(4), Ok(()));
+        assert_eq!(cursor.current(), Some(&4));
+        assert_eq!(
+            cursor.move_by(1),
+            Err(MoveError { offset: 1, index: Some(4), len: 5 })
+        );
+        assert_eq!(cursor.move_by(-4), Ok(()));
+        assert_eq!(cursor.current(), Some(&0));
+        assert!(cursor.move_by(-1).is_err());
+        assert!(cursor.move_by(isize::MIN).is_err());
+        assert!(cursor.move_by(isize::MAX).is_err());
+        assert_eq!(cursor.index(), Some(0));
+
+        cursor.move_to_ghost();
+        assert_eq!(cursor.move_by(2), Ok(()));
+        assert_eq!(cursor.current(), Some(&1));
+
+        cursor.move_to_ghost();
+        assert_eq!(cursor.move_by(-2), Ok(()));
+        assert_eq!(cursor.current(), Some(&3));
+
+        cursor.move_to_ghost();
+        assert_eq!(
+            cursor.move_by(-6),
+            Err(MoveError { offset: -6, index: None, len: 5 })
+        );
+        assert_eq!(cursor.move_by(0), Ok(()));
+        assert_eq!(cursor.current(), None);
+    }
+
+    #[test]
+    fn test_cursor_move_by_clamped() {
+        let list = LinkedList::from([0, 1, 2, 3, 4]);
+        let mut cursor = list.cursor_front();
+
+        cursor.move_by_clamped(isize::MAX);
+        assert_eq!(cursor.index(), Some(4));
+        cursor.move_by_clamped(-2);
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_by_clamped(isize::MIN);
+        assert_eq!(cursor.index(), Some(0));
+
+        cursor.move_to_ghost();
+        cursor.move_by_clamped(-10);
+        assert_eq!(cursor.index(), Some(0));
+
+        cursor.move_to_ghost();
+        cursor.move_by_clamped(10);
+        assert_eq!(cursor.index(), Some(4));
+
+        cursor.move_to_ghost();
+        cursor.move_by_clamped(0);
+        assert_eq!(cursor.index(), None);
+
+        let empty = LinkedList::<i32>::new();
+        let mut cursor = empty.cursor_front();
+        cursor.move_by_clamped(3);
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index, 0);
+    }
+
+    #[test]
+    fn test_cursor_move_to_ends() {
+        let list = LinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_front();
+
+        cursor.move_to_back();
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(2));
+
+        cursor.move_to_ghost();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index, 3);
+
+        cursor.move_to_front();
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(cursor.index(), Some(0));
+    }
+
+    #[test]
+    fn test_cursor_mut_seek() {
+        let mut list = LinkedList::from_iter(0..10);
+        let mut cursor = list.cursor_front_mut();
+
+        assert_eq!(cursor.seek(8), Ok(()));
+        *cursor.current().unwrap() = 80;
+        assert_eq!(cursor.move_by(-5), Ok(()));
+        *cursor.current().unwrap() = 30;
+        assert!(cursor.move_by(7).is_err());
+        cursor.move_by_clamped(7);
+        assert_eq!(cursor.index(), Some(9));
+
+        cursor.move_to_front();
+        cursor.delete();
+        cursor.move_to_back();
+        assert_eq!(cursor.index(), Some(8));
+        cursor.move_to_ghost();
+        cursor.insert_before(10);
+        assert_eq!(cursor.seek(10), Err(IndexError { index: 10, len: 10 }));
+        assert_eq!(cursor.seek(9), Ok(()));
+        assert_eq!(cursor.current(), Some(&mut 10));
+
+        assert_eq!(list, [1, 2, 30, 4, 5, 6, 7, 80, 9, 10]);
+    }
+
+    #[test]
+    fn test_cursor_iter_forward_backward() {
+        let list = LinkedList::from([1, 2, 3, 4, 5]);
+        let mut cursor = list.cursor_front();
+        cursor.seek(2).unwrap();
+
+        let mut forward = cursor.iter_forward();
+        assert_eq!(forward.len(), 3);
+        assert_eq!(forward.next_back(), Some(&5));
+        assert_eq!(forward.len(), 2);
+        assert_eq!(forward.collect::<Vec<_>>(), vec![&3, &4]);
+
+        let mut backward = cursor.iter_backward();
+        assert_eq!(backward.len(), 3);
+        assert_eq!(backward.next_back(), Some(&1));
+        assert_eq!(backward.collect::<Vec<_>>(), vec![&3, &2]);
+
+        cursor.move_to_back();
+        assert_eq!(cursor.iter_forward().collect::<Vec<_>>(), vec![&5]);
+        assert_eq!(cursor.iter_backward().len(), 5);
+
+        cursor.move_to_ghost();
+        assert_eq!(cursor.iter_forward().next(), None);
+        assert_eq!(cursor.iter_backward().next(), None);
+        assert_eq!(cursor.iter_forward().len(), 0);
+        assert_eq!(cursor.iter_backward().len(), 0);
+    }
+
+    #[test]
+    fn test_cursor_mut_iter_mut_forward() {
+        let mut list = LinkedList::from([1, 2, 3, 4]);
+        let mut cursor = list.cursor_back_mut();
+        cursor.move_prev();
+
+        let mut iter = cursor.iter_mut_forward();
+        assert_eq!(iter.len(), 2);
+        *iter.next().unwrap() = 30;
+        *iter.next().unwrap() = 40;
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(cursor.current(), Some(&mut 30));
+        cursor.move_to_ghost();
+        assert_eq!(cursor.iter_mut_forward().len(), 0);
+
+        assert_eq!(list, [1, 2, 30, 40]);
+    }
+
+    #[test]
+    fn test_cursor_clone_and_compare() {
+        let list = LinkedList::from([1, 2, 3]);
+        let mut first = list.cursor_front();
+        let second = first;
+        let mut ghost = first;
+        ghost.move_to_ghost();
+
+        assert_eq!(first, second);
+        first.move_next();
+        assert_ne!(first, second);
+        assert_eq!(second.current(), Some(&1));
+
+        assert!(second < first);
+        assert!(first < ghost);
+        assert!(ghost >= ghost);
+        assert_eq!(first.partial_cmp(&second), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn test_cursor_compare_different_lists() {
+        let list = LinkedList::from([1, 2, 3]);
+        let other = LinkedList::from([1, 2, 3]);
+        let cursor = list.cursor_front();
+        let other_cursor = other.cursor_front();
+
+        assert_ne!(cursor, other_cursor);
+        assert_eq!(cursor.partial_cmp(&other_cursor), None);
+        assert_eq!(other_cursor.partial_cmp(&cursor), None);
+    }
+
+    #[test]
+    fn test_cursor_debug() {
+        let list = LinkedList::from([1, 2]);
+        let mut cursor = list.cursor_back();
+
+        assert_eq!(format!("{cursor:?}"), "Cursor([1, 2], Some(1))");
+        cursor.move_next();
+        assert_eq!(format!("{cursor:?}"), "Cursor([1, 2], None)");
+    }
+
+    #[test]
+    fn test_cursor_distance_to() {
+        let list = LinkedList::from([1, 2, 3]);
+        let front = list.cursor_front();
+        let back = list.cursor_back();
+        let mut ghost = front;
+        ghost.move_prev();
+
+        assert_eq!(front.distance_to(&front), 0);
+        assert_eq!(front.distance_to(&back), 2);
+        assert_eq!(back.distance_to(&front), -2);
+        assert_eq!(front.distance_to(&ghost), 3);
+        assert_eq!(ghost.distance_to(&back), -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cursors point into different lists")]
+    fn test_cursor_distance_to_other_list() {
+        let list = LinkedList::from([1]);
+        let other = LinkedList::from([1]);
+
+        list.cursor_front().distance_to(&other.cursor_front());
+    }
+
+    #[test]
+    fn test_cursor_iter_until() {
+        let list = LinkedList::from([1, 2, 3, 4]);
+        let front = list.cursor_front();
+        let mut ghost = front;
+        ghost.move_to_ghost();
+        let mut third = front;
+        third.seek(2).unwrap();
+
+        assert_eq!(front.iter_until(&front).next(), None);
+        assert_eq!(front.iter_until(&third).collect::<Vec<_>>(), vec![&1, &2]);
+        assert_eq!(third.iter_until(&ghost).rev().collect::<Vec<_>>(), vec![&4, &3]);
+        assert_eq!(front.iter_until(&ghost).len(), 4);
+        assert_eq!(ghost.iter_until(&ghost).len(), 0);
+
+        let empty = LinkedList::<i32>::new();
+        let cursor = empty.cursor_front();
+        assert_eq!(cursor.iter_until(&cursor).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "cursor end index 0 should be >= cursor start index 1")]
+    fn test_cursor_iter_until_backwards() {
+        let list = LinkedList::from([1, 2]);
+        let back = list.cursor_back();
+
+        back.iter_until(&list.cursor_front()).for_each(drop);
+    }
+
+    #[test]
+    fn test_cursor_mut_into_current() {
+        let mut list = LinkedList::from([1, 2, 3]);
+
+        let mut cursor = list.cursor_back_mut();
+        *cursor.current().unwrap() += 1;
+        *cursor.peek_prev().unwrap() += 1;
+        let last = cursor.into_current().unwrap();
+        *last *= 10;
+
+        assert_eq!(list, [1, 3, 40]);
+        assert_eq!(list.cursor_front_mut().into_current(), Some(&mut 1));
+        assert_eq!(LinkedList::<i32>::new().cursor_front_mut().into_current(), None);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut list = LinkedList::from([1, 2]);
+        let mut other = LinkedList::from([3, 4]);
+
+        list.append(&mut other);
+
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.back(), Some(&4));
+        assert!(other.is_empty());
+        assert_eq!(other.front(), None);
+
+        let mut empty = LinkedList::new();
+        empty.append(&mut list);
+        list.append(&mut LinkedList::new());
+
+        assert!(list.is_empty());
+        assert_eq!(empty.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_prepend() {
+        let mut list = LinkedList::from([3, 4]);
+        let mut other = LinkedList::from([1, 2]);
+
+        list.prepend(&mut other);
+
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.front(), Some(&1));
+        assert!(other.is_empty());
+
+        let values = list.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(values, vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_split_off() {
+        for at in 0..=5 {
+            let mut list = LinkedList::from([0, 1, 2, 3, 4]);
+            let second = list.split_off(at);
+
+            assert_eq!(list.len(), at);
+            assert_eq!(second.len(), 5 - at);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), (0..at).collect::<Vec<_>>());
+            assert_eq!(list.iter().rev().count(), at);
+            assert_eq!(second.iter().copied().collect::<Vec<_>>(), (at..5).collect::<Vec<_>>());
+            assert_eq!(second.iter().rev().count(), 5 - at);
         }
     }
-}
 
-impl<'a, T: 'a> DoubleEndedIterator for IterMut<'a, T> {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.len == 0 {
-            None
-        } else {
-            self.tail.map(|node| unsafe {
-                let node = &mut *node.as_ptr();
-                self.len -= 1;
-                self.tail = node.prev;
-                &mut node.element
+    #[test]
+    #[should_panic]
+    fn test_split_off_out_of_bounds() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        list.split_off(4);
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut list = LinkedList::from([1, 2, 3, 4, 5, 6]);
+        list.retain(|x| *x % 3 != 0);
+
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&5));
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![5, 4, 2, 1]);
+
+        list.retain(|_| false);
+
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn test_retain_mut() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        list.retain_mut(|x| {
+            *x += 1;
+            *x != 3
+        });
+
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![2, 4]);
+    }
+
+    #[test]
+    fn test_retain_when_predicate_panics() {
+        let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.retain(|x| {
+                if *x == 4 {
+                    panic!("predicate panicked");
+                }
+                *x != 2
             })
-        }
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 3, 4, 5]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![5, 4, 3, 1]);
     }
-}
 
-/// Moves the cursor to the next element in the list.
-fn move_next<T>(current: &mut Link<T>, index: &mut usize, list: &LinkedList<T>) {
-    match current {
-        None => {
-            *current = list.head;
-            *index = 0;
-        }
-        Some(node) => unsafe {
-            *current = node.as_ref().next;
-            *index += 1;
-        },
+    #[test]
+    fn test_extract_if() {
+        let mut list = LinkedList::from([1, 2, 3, 4, 5, 6]);
+
+        let extracted = list.extract_if(|x| *x % 2 == 1).collect::<Vec<_>>();
+
+        assert_eq!(extracted, vec![1, 3, 5]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some(&2));
+        assert_eq!(list.back(), Some(&6));
     }
-}
 
-/// Moves the cursor to the previous element in the list.
-fn move_prev<T>(current: &mut Link<T>, index: &mut usize, list: &LinkedList<T>) {
-    match current {
-        None => {
-            *current = list.tail;
-            *index = list.len().saturating_sub(1)
+    #[test]
+    fn test_extract_if_dropped_early() {
+        let mut list = LinkedList::from([1, 2, 3, 4, 5, 6]);
+
+        {
+            let mut iter = list.extract_if(|x| *x > 4 || *x == 2);
+            assert_eq!(iter.size_hint(), (0, Some(6)));
+            assert_eq!(iter.next(), Some(2));
+            assert_eq!(iter.size_hint(), (0, Some(4)));
         }
-        Some(node) => unsafe {
-            *current = node.as_ref().prev;
-            *index = index.saturating_sub(1)
-        },
+
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 3, 4, 5, 6]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![6, 5, 4, 3, 1]);
     }
-}
 
-impl<'a, T: 'a> Cursor<'a, T> {
-    /// Returns the current index if the cursor is pointing to an element.
-    #[inline]
-    pub fn index(&self) -> Option<usize> {
-        self.current.map(|_| self.index)
+    #[test]
+    fn test_drain() {
+        let mut list = LinkedList::from([1, 2, 3, 4, 5, 6]);
+
+        let mut drain = list.drain(1..5);
+        assert_eq!(drain.len(), 4);
+        assert_eq!(drain.next(), Some(2));
+        assert_eq!(drain.next_back(), Some(5));
+        assert_eq!(drain.len(), 2);
+        drop(drain);
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 6]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![6, 1]);
     }
 
-    /// Returns a reference to the current element, if any.
-    #[inline]
-    pub fn current(&mut self) -> Option<&'a T> {
-        self.current
-            .map(|node| unsafe { &(*node.as_ptr()).element })
+    #[test]
+    fn test_drain_bounds() {
+        let mut list = LinkedList::from([1, 2, 3, 4]);
+        assert_eq!(list.drain(..2).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(list.front(), Some(&3));
+
+        let mut list = LinkedList::from([1, 2, 3, 4]);
+        assert_eq!(list.drain(2..).collect::<Vec<_>>(), vec![3, 4]);
+        assert_eq!(list.back(), Some(&2));
+
+        let mut list = LinkedList::from([1, 2, 3, 4]);
+        assert_eq!(list.drain(2..2).count(), 0);
+        assert_eq!(list.len(), 4);
+
+        assert_eq!(list.drain(..).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
     }
 
-    /// Moves the cursor to the next element in the list.
-    pub fn move_next(&mut self) {
-        move_next(&mut self.current, &mut self.index, self.list);
+    #[test]
+    #[should_panic]
+    fn test_drain_out_of_bounds() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        list.drain(1..4);
     }
 
-    /// Moves the cursor to the previous element in the list.
-    pub fn move_prev(&mut self) {
-        move_prev(&mut self.current, &mut self.index, self.list);
+    #[test]
+    fn test_splice() {
+        let mut list = LinkedList::from([1, 2, 3, 4]);
+        let removed = list.splice(1..3, [10, 20, 30]).collect::<Vec<_>>();
+
+        assert_eq!(removed, vec![2, 3]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 10, 20, 30, 4]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 30, 20, 10, 1]);
     }
 
-    /// Peeks at the next element without moving the cursor.
-    pub fn peek_next(&self) -> Option<&'a T> {
-        unsafe {
-            let next = match self.current {
-                None => self.list.head,
-                Some(node) => node.as_ref().next,
-            };
-            next.map(|node| &(*node.as_ptr()).element)
-        }
+    #[test]
+    fn test_splice_at_ends() {
+        let mut list = LinkedList::from([1, 2, 3]);
+
+        assert_eq!(list.splice(0..0, [0]).count(), 0);
+        assert_eq!(list.splice(4.., [4, 5]).count(), 0);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(list.back(), Some(&5));
+
+        let removed = list.splice(.., std::iter::empty()).collect::<Vec<_>>();
+        assert_eq!(removed, vec![0, 1, 2, 3, 4, 5]);
+        assert!(list.is_empty());
+
+        assert_eq!(list.splice(.., [7]).count(), 0);
+        assert_eq!(list.front(), Some(&7));
+        assert_eq!(list.back(), Some(&7));
     }
 
-    /// Peeks at the previous element without moving the cursor.
-    pub fn peek_prev(&self) -> Option<&'a T> {
-        unsafe {
-            let prev = match self.current {
-                None => self.list.tail,
-                Some(current) => current.as_ref().prev,
-            };
-            prev.map(|node| &(*node.as_ptr()).element)
+    /// Generates a deterministic sequence of pseudo-random numbers in `0..modulo`.
+    fn pseudo_random(len: usize, modulo: u64) -> Vec<u64> {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 33) % modulo
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sort() {
+        for len in [0, 1, 2, 3, 7, 64, 100, 1000] {
+            let values = pseudo_random(len, 50);
+            let mut list = values.iter().copied().collect::<LinkedList<_>>();
+            let mut expected = values.clone();
+
+            list.sort();
+            expected.sort();
+
+            assert_eq!(list.len(), len);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            expected.reverse();
+            assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), expected);
         }
     }
-}
 
-impl<'a, T: 'a> CursorMut<'a, T> {
-    /// Returns the current index if the cursor is pointing to an element.
-    #[inline]
-    pub fn index(&mut self) -> Option<usize> {
-        let _ = self.current?;
-        Some(self.index)
+    #[test]
+    fn test_sort_by_is_stable() {
+        let values = pseudo_random(500, 10)
+            .into_iter()
+            .enumerate()
+            .map(|(index, key)| (key, index))
+            .collect::<Vec<_>>();
+
+        let mut list = values.iter().copied().collect::<LinkedList<_>>();
+        list.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut expected = values.clone();
+        expected.sort_by_key(|pair| pair.0);
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
     }
 
-    /// Returns a mutable reference to the current element, if any.
-    #[inline]
-    pub fn current(&mut self) -> Option<&'a mut T> {
-        self.current
-            .map(|node| unsafe { &mut (*node.as_ptr()).element })
+    #[test]
+    fn test_sort_by_key() {
+        let mut list = LinkedList::from([(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]);
+        list.sort_by_key(|pair| pair.0);
+
+        let values = list.into_iter().map(|pair| pair.1).collect::<Vec<_>>();
+        assert_eq!(values, vec!['b', 'd', 'a', 'c']);
     }
 
-    /// Moves the cursor to the next element in the list.
-    pub fn move_next(&mut self) {
-        move_next(&mut self.current, &mut self.index, self.list);
+    #[test]
+    fn test_sort_by_cached_key() {
+        let values = pseudo_random(300, 20);
+        let mut list = values.iter().copied().enumerate().collect::<LinkedList<_>>();
+        let mut calls = 0;
+
+        list.sort_by_cached_key(|pair| {
+            calls += 1;
+            pair.1
+        });
+
+        let mut expected = values.into_iter().enumerate().collect::<Vec<_>>();
+        expected.sort_by_key(|pair| pair.1);
+
+        assert_eq!(calls, 300);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        expected.reverse();
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), expected);
     }
 
-    /// Moves the cursor to the previous element in the list.
-    pub fn move_prev(&mut self) {
-        move_prev(&mut self.current, &mut self.index, self.list);
+    #[test]
+    fn test_sort_when_comparator_panics() {
+        let values = pseudo_random(200, 1000);
+        let mut list = values.iter().copied().collect::<LinkedList<_>>();
+        let mut comparisons = 0;
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                comparisons += 1;
+                if comparisons == 500 {
+                    panic!("comparator panicked");
+                }
+                a.cmp(b)
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(list.len(), 200);
+
+        let mut forward = list.iter().copied().collect::<Vec<_>>();
+        let mut backward = list.iter().rev().copied().collect::<Vec<_>>();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        let mut expected = values;
+        forward.sort();
+        expected.sort();
+        assert_eq!(forward, expected);
     }
 
-    /// Peeks at the next element without moving the cursor.
-    pub fn peek_next(&self) -> Option<&'a mut T> {
-        unsafe {
-            let next = match self.current {
-                None => self.list.head,
-                Some(current) => current.as_ref().next,
-            };
+    #[test]
+    fn test_is_sorted() {
+        assert!(LinkedList::<i32>::new().is_sorted());
+        assert!(LinkedList::from([1]).is_sorted());
+        assert!(LinkedList::from([1, 1, 2]).is_sorted());
+        assert!(!LinkedList::from([1, 3, 2]).is_sorted());
+        assert!(LinkedList::from([3, 2, 2]).is_sorted_by(|a, b| a >= b));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut list = LinkedList::from([1, 4, 6, 9]);
+        let mut other = LinkedList::from([2, 3, 6, 10, 11]);
+
+        list.merge(&mut other);
+
+        assert!(other.is_empty());
+        assert_eq!(other.front(), None);
+        assert_eq!(list.len(), 9);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 6, 6, 9, 10, 11]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![11, 10, 9, 6, 6, 4, 3, 2, 1]);
+
+        let mut empty = LinkedList::new();
+        empty.merge(&mut list);
+        assert_eq!(empty.len(), 9);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_merge_by_is_stable() {
+        let mut list = LinkedList::from([(1, 'a'), (2, 'a'), (2, 'b')]);
+        let mut other = LinkedList::from([(1, 'c'), (2, 'c'), (3, 'c')]);
+
+        list.merge_by(&mut other, |a, b| a.0.cmp(&b.0));
+
+        let values = list.into_iter().map(|pair| pair.1).collect::<String>();
+        assert_eq!(values, "acabcc");
+    }
+
+    #[test]
+    fn test_merge_when_comparator_panics() {
+        let mut list = LinkedList::from([1, 3, 5, 7]);
+        let mut other = LinkedList::from([2, 4, 6, 8]);
+        let mut comparisons = 0;
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.merge_by(&mut other, |a, b| {
+                comparisons += 1;
+                if comparisons == 4 {
+                    panic!("comparator panicked");
+                }
+                a.cmp(b)
+            })
+        }));
+
+        assert!(result.is_err());
+        assert!(other.is_empty());
+        assert_eq!(list.len(), 8);
 
-            next.map(|node| &mut (*node.as_ptr()).element)
-        }
+        let mut values = list.iter().copied().collect::<Vec<_>>();
+        assert_eq!(list.iter().rev().count(), 8);
+        values.sort();
+        assert_eq!(values, vec![1, 2, 3, 4, 5, 6, 7, 8]);
     }
 
-    /// Peeks at the previous element without moving the cursor.
-    pub fn peek_prev(&self) -> Option<&'a mut T> {
-        unsafe {
-            let prev = match self.current {
-                None => self.list.tail,
-                Some(current) => current.as_ref().prev,
-            };
+    #[test]
+    fn test_merge_all() {
+        let lists = (0..7)
+            .map(|shard| (0..10).map(|i| (i * 7 + shard, shard)).collect::<LinkedList<_>>())
+            .chain([LinkedList::new()])
+            .collect::<Vec<_>>();
 
-            prev.map(|node| &mut (*node.as_ptr()).element)
-        }
+        let merged = LinkedList::merge_all(lists);
+
+        assert_eq!(merged.len(), 70);
+        assert!(merged.is_sorted());
+        assert_eq!(merged.iter().rev().count(), 70);
+
+        assert!(LinkedList::<i32>::merge_all(Vec::new()).is_empty());
     }
 
-    /// Deletes the current element and moves the cursor to the next element.
-    pub fn delete(&mut self) -> Option<T> {
-        unsafe {
-            self.current.map(|node| {
-                let current = Box::from_raw(node.as_ptr());
-                let prev = current.prev;
-                let next = current.next;
-
-                match (prev, next) {
-                    // if the linked list has only one element
-                    (None, None) => {
-                        self.list.head = None;
-                        self.list.tail = None;
-                        self.list.len = 0;
-                    }
-                    // if the current is the tail
-                    (Some(prev), None) => {
-                        (*prev.as_ptr()).next = None;
-                        self.list.tail = Some(prev);
-                        self.list.len -= 1;
-                    }
-                    // if the current is the head
-                    (None, Some(next)) => {
-                        (*next.as_ptr()).prev = None;
-                        self.list.head = Some(next);
-                        self.list.len -= 1;
-                    }
-                    // if the list has both an element before it and an element after it
-                    (Some(prev), Some(next)) => {
-                        (*prev.as_ptr()).next = Some(next);
-                        (*next.as_ptr()).prev = Some(prev);
-                        self.list.len -= 1;
-                    }
-                }
+    #[test]
+    fn test_union() {
+        let first = LinkedList::from([1, 3, 5, 7]);
+        let second = LinkedList::from([2, 3, 4, 7, 8, 9]);
 
-                self.current = next;
-                current.element
-            })
-        }
+        let union = first.union(second);
+
+        assert_eq!(union.len(), 8);
+        assert_eq!(union.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 7, 8, 9]);
+        assert_eq!(union.iter().rev().copied().collect::<Vec<_>>(), vec![9, 8, 7, 5, 4, 3, 2, 1]);
     }
 
-    /// Inserts an element before the current position.
-    pub fn insert_before(&mut self, element: T) {
-        match self.current {
-            None => {
-                self.list.push_front(element);
-            }
-            Some(current) => unsafe {
-                let prev = current.as_ref().prev;
+    #[test]
+    fn test_union_by_keeps_elements_of_self() {
+        let first = LinkedList::from([(1, 'a'), (2, 'a')]);
+        let second = LinkedList::from([(2, 'b'), (3, 'b')]);
 
-                match prev {
-                    None => {
-                        self.list.push_front(element);
-                    }
-                    Some(prev) => {
-                        let node = NonNull::from(Box::leak(Box::new(Node::new(element))));
-                        (*prev.as_ptr()).next = Some(node);
-                        (*node.as_ptr()).prev = Some(prev);
-                        (*node.as_ptr()).next = Some(current);
-                        (*current.as_ptr()).prev = Some(node);
-                        self.list.len += 1;
-                    }
-                }
+        let union = first.union_by(second, |a, b| a.0.cmp(&b.0));
 
-                self.index += 1;
-            },
-        }
+        assert_eq!(union.into_iter().collect::<Vec<_>>(), vec![(1, 'a'), (2, 'a'), (3, 'b')]);
     }
 
-    /// Inserts an element after the current position.
-    pub fn insert_after(&mut self, element: T) {
-        match self.current {
-            None => {
-                self.list.push_back(element);
-            }
-            Some(current) => unsafe {
-                let next = current.as_ref().next;
+    #[test]
+    fn test_intersection() {
+        let first = LinkedList::from([1, 3, 5, 7, 9]);
+        let second = LinkedList::from([2, 3, 4, 7, 8]);
 
-                match next {
-                    None => {
-                        self.list.push_back(element);
-                    }
-                    Some(next) => {
-                        let node = NonNull::from(Box::leak(Box::new(Node::new(element))));
-                        (*current.as_ptr()).next = Some(node);
-                        (*node.as_ptr()).prev = Some(current);
-                        (*node.as_ptr()).next = Some(next);
-                        (*next.as_ptr()).prev = Some(node);
-                        self.list.len += 1;
-                    }
-                }
-            },
-        }
+        let intersection = first.intersection(second);
+
+        assert_eq!(intersection.len(), 2);
+        assert_eq!(intersection.back(), Some(&7));
+        assert_eq!(intersection.into_iter().collect::<Vec<_>>(), vec![3, 7]);
+
+        let empty = LinkedList::from([1, 2]).intersection(LinkedList::new());
+        assert!(empty.is_empty());
     }
-}
 
-impl<T> Iterator for IntoIter<T> {
-    type Item = T;
+    #[test]
+    fn test_difference() {
+        let first = LinkedList::from([1, 3, 5, 7, 9]);
+        let second = LinkedList::from([2, 3, 4, 7, 8]);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.list.pop_front()
+        let difference = first.difference(second);
+
+        assert_eq!(difference.len(), 3);
+        assert_eq!(difference.iter().rev().copied().collect::<Vec<_>>(), vec![9, 5, 1]);
     }
-}
 
-impl<T> DoubleEndedIterator for IntoIter<T> {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.list.pop_back()
+    #[test]
+    fn test_symmetric_difference() {
+        let first = LinkedList::from([1, 3, 5, 7, 9]);
+        let second = LinkedList::from([2, 3, 4, 7, 8]);
+
+        let symmetric_difference = first.symmetric_difference(second);
+
+        assert_eq!(symmetric_difference.len(), 6);
+        assert_eq!(
+            symmetric_difference.into_iter().collect::<Vec<_>>(),
+            vec![1, 2, 4, 5, 8, 9]
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_set_operations_free_dropped_elements() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let first = [1, 2, 3].map(|i| (i, Rc::clone(&tracker)));
+        let second = [2, 3, 4].map(|i| (i, Rc::clone(&tracker)));
+
+        let intersection = LinkedList::from(first)
+            .intersection_by(LinkedList::from(second), |a, b| a.0.cmp(&b.0));
+
+        assert_eq!(intersection.len(), 2);
+        assert_eq!(Rc::strong_count(&tracker), 3);
+
+        drop(intersection);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
 
     #[test]
-    fn test_push_front() {
-        let mut list = LinkedList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+    fn test_dedup() {
+        let mut list = LinkedList::from([1, 1, 1, 2, 3, 3, 4, 4]);
+        list.dedup();
 
-        assert_eq!(list.pop_front(), Some(1));
-        assert_eq!(list.pop_front(), Some(2));
-        assert_eq!(list.pop_front(), Some(3));
-        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+
+        let mut empty = LinkedList::<i32>::new();
+        empty.dedup();
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn test_push_back() {
-        let mut list = LinkedList::new();
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
+    fn test_dedup_by() {
+        let mut list = LinkedList::from([1, 2, 4, 5, 7]);
+        let mut calls = Vec::new();
 
-        assert_eq!(list.pop_back(), Some(3));
-        assert_eq!(list.pop_back(), Some(2));
-        assert_eq!(list.pop_back(), Some(1));
-        assert_eq!(list.pop_back(), None);
+        list.dedup_by(|current, retained| {
+            calls.push((*current, *retained));
+            *current - *retained == 1
+        });
+
+        assert_eq!(calls, vec![(2, 1), (4, 1), (5, 4), (7, 4)]);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 4, 7]);
     }
 
     #[test]
-    fn test_front() {
-        let mut list = LinkedList::new();
+    fn test_dedup_by_key() {
+        let mut list = LinkedList::from([(1, 'a'), (1, 'b'), (2, 'c'), (2, 'd'), (1, 'e')]);
+        list.dedup_by_key(|pair| pair.0);
 
-        list.push_front(3);
-        assert_eq!(list.front(), Some(&3));
+        assert_eq!(list.back(), Some(&(1, 'e')));
+        assert_eq!(list.into_iter().map(|pair| pair.1).collect::<String>(), "ace");
+    }
 
-        list.push_front(2);
-        assert_eq!(list.front(), Some(&2));
+    #[test]
+    fn test_dedup_all() {
+        let mut list = LinkedList::from(["b", "a", "b", "c", "a", "c", "d"]);
+        list.dedup_all();
 
-        list.push_front(1);
-        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec!["b", "a", "c", "d"]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec!["d", "c", "a", "b"]);
     }
 
     #[test]
-    fn test_back() {
-        let mut list = LinkedList::new();
+    fn test_reverse() {
+        let mut list = LinkedList::from([1, 2, 3, 4]);
+        list.reverse();
 
-        list.push_back(1);
+        assert_eq!(list.front(), Some(&4));
         assert_eq!(list.back(), Some(&1));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
 
-        list.push_back(2);
-        assert_eq!(list.back(), Some(&2));
+        let mut single = LinkedList::from([1]);
+        single.reverse();
+        assert_eq!(single.front(), single.back());
 
-        list.push_back(3);
-        assert_eq!(list.back(), Some(&3));
+        let mut empty = LinkedList::<i32>::new();
+        empty.reverse();
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn test_front_mut() {
-        let mut list = LinkedList::new();
+    fn test_reverse_range() {
+        for start in 0..=5 {
+            for end in start..=5 {
+                let mut list = LinkedList::from([0, 1, 2, 3, 4]);
+                list.reverse_range(start..end);
+
+                let mut expected = vec![0, 1, 2, 3, 4];
+                expected[start..end].reverse();
+
+                assert_eq!(list.len(), 5);
+                assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+                expected.reverse();
+                assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), expected);
+            }
+        }
+    }
 
-        list.push_front(3);
-        assert_eq!(list.front_mut(), Some(&mut 3));
+    #[test]
+    #[should_panic]
+    fn test_reverse_range_out_of_bounds() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        list.reverse_range(..=3);
+    }
 
-        if let Some(element) = list.front_mut() {
-            *element = 2;
+    #[test]
+    fn test_rotate_left() {
+        for k in 0..=5 {
+            let mut list = LinkedList::from([0, 1, 2, 3, 4]);
+            list.rotate_left(k);
+
+            let mut expected = vec![0, 1, 2, 3, 4];
+            expected.rotate_left(k);
+
+            assert_eq!(list.len(), 5);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            expected.reverse();
+            assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), expected);
         }
-
-        assert_eq!(list.pop_front(), Some(2));
     }
 
     #[test]
-    fn test_back_mut() {
-        let mut list = LinkedList::new();
+    fn test_rotate_right() {
+        for k in 0..=5 {
+            let mut list = LinkedList::from([0, 1, 2, 3, 4]);
+            list.rotate_right(k);
 
-        list.push_back(1);
-        assert_eq!(list.back_mut(), Some(&mut 1));
+            let mut expected = vec![0, 1, 2, 3, 4];
+            expected.rotate_right(k);
 
-        if let Some(elem) = list.back_mut() {
-            *elem = 2;
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            expected.reverse();
+            assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), expected);
         }
 
-        assert_eq!(list.pop_back(), Some(2));
+        let mut empty = LinkedList::<i32>::new();
+        empty.rotate_right(0);
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn test_iter() {
-        let list = LinkedList::from([1, 2, 3, 4, 5]);
-
-        let mut iter = list.iter();
+    #[should_panic]
+    fn test_rotate_left_out_of_bounds() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        list.rotate_left(4);
+    }
 
-        assert_eq!(iter.next(), Some(&1));
-        assert_eq!(iter.next(), Some(&2));
-        assert_eq!(iter.next_back(), Some(&5));
-        assert_eq!(iter.next_back(), Some(&4));
-        assert_eq!(iter.next(), Some(&3));
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next_back(), None);
+    #[test]
+    #[should_panic]
+    fn test_rotate_right_out_of_bounds() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        list.rotate_right(4);
     }
 
     #[test]
-    fn test_iter_mut() {
+    fn test_get() {
         let mut list = LinkedList::from([1, 2, 3, 4, 5]);
 
-        let mut iter = list.iter_mut();
+        for index in 0..5 {
+            assert_eq!(list.get(index), Some(&(index + 1)));
+        }
+        assert_eq!(list.get(5), None);
 
-        assert_eq!(iter.next(), Some(&mut 1));
-        assert_eq!(iter.next(), Some(&mut 2));
-        assert_eq!(iter.next_back(), Some(&mut 5));
-        assert_eq!(iter.next_back(), Some(&mut 4));
-        assert_eq!(iter.next(), Some(&mut 3));
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next_back(), None);
+        *list.get_mut(3).unwrap() = 40;
+        assert_eq!(list.get(3), Some(&40));
+        assert_eq!(list.get_mut(5), None);
     }
 
     #[test]
-    fn test_len() {
-        let mut list = LinkedList::from([1, 2, 3]);
-        assert_eq!(list.len(), 3);
-        list.pop_back();
-        assert_eq!(list.len(), 2);
-        list.pop_front();
-        assert_eq!(list.len(), 1);
-        list.pop_front();
-        assert_eq!(list.len(), 0);
+    fn test_insert() {
+        for index in 0..=4 {
+            let mut list = LinkedList::from([0, 1, 2, 3]);
+            list.insert(index, 10);
+
+            let mut expected = vec![0, 1, 2, 3];
+            expected.insert(index, 10);
+
+            assert_eq!(list.len(), 5);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            expected.reverse();
+            assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), expected);
+        }
     }
 
     #[test]
-    fn test_is_empty() {
+    fn test_try_insert() {
         let mut list = LinkedList::new();
-        assert!(list.is_empty());
-        list.push_back(1);
-        assert!(!list.is_empty());
-        list.pop_back();
+
+        assert_eq!(list.try_insert(0, 'a'), Ok(()));
+
+        let err = list.try_insert(2, 'b').unwrap_err();
+        let kind = InsertErrorKind::OutOfBounds { index: 2, len: 1 };
+        assert_eq!(err, InsertError { element: 'b', kind });
+        assert_eq!(err.to_string(), "insertion index 2 is out of bounds for a list of length 1");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_out_of_bounds() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        list.insert(4, 4);
+    }
+
+    #[test]
+    fn test_remove() {
+        for index in 0..4 {
+            let mut list = LinkedList::from([0, 1, 2, 3]);
+            assert_eq!(list.remove(index), index);
+
+            let mut expected = vec![0, 1, 2, 3];
+            expected.remove(index);
+
+            assert_eq!(list.len(), 3);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            expected.reverse();
+            assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn test_try_remove() {
+        let mut list = LinkedList::from([1]);
+
+        assert_eq!(list.try_remove(1), Err(IndexError { index: 1, len: 1 }));
+        assert_eq!(list.try_remove(0), Ok(1));
+        assert_eq!(list.try_remove(0), Err(IndexError { index: 0, len: 0 }));
         assert!(list.is_empty());
     }
 
     #[test]
-    fn test_from_iter() {
-        let elements = [1, 2, 3];
-        let mut list = LinkedList::from_iter(elements.iter());
+    #[should_panic]
+    fn test_remove_out_of_bounds() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        list.remove(3);
+    }
 
-        assert_eq!(list.pop_front(), Some(&1));
-        assert_eq!(list.pop_front(), Some(&2));
-        assert_eq!(list.pop_front(), Some(&3));
+    #[test]
+    fn test_swap_remove() {
+        for index in 0..4 {
+            let mut list = LinkedList::from([0, 1, 2, 3]);
+            let mut expected = std::collections::VecDeque::from([0, 1, 2, 3]);
+
+            assert_eq!(list.swap_remove_front(index), expected.swap_remove_front(index));
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), Vec::from(expected.clone()));
+            assert_eq!(list.iter().rev().count(), 3);
+
+            let mut list = LinkedList::from([0, 1, 2, 3]);
+            let mut expected = std::collections::VecDeque::from([0, 1, 2, 3]);
+
+            assert_eq!(list.swap_remove_back(index), expected.swap_remove_back(index));
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), Vec::from(expected.clone()));
+            assert_eq!(list.iter().rev().count(), 3);
+        }
+
+        let mut list = LinkedList::from([1]);
+        assert_eq!(list.swap_remove_back(1), None);
+        assert_eq!(list.swap_remove_front(0), Some(1));
+        assert!(list.is_empty());
     }
 
     #[test]
-    fn test_into_iter() {
-        let list = LinkedList::from([1, 2, 3, 4, 5]);
-        let mut iter = list.into_iter();
+    fn test_swap() {
+        for i in 0..5 {
+            for j in 0..5 {
+                let mut list = LinkedList::from([0, 1, 2, 3, 4]);
+                list.swap(i, j);
+
+                let mut expected = vec![0, 1, 2, 3, 4];
+                expected.swap(i, j);
+
+                assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+                expected.reverse();
+                assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), expected);
+            }
+        }
+    }
 
-        assert_eq!(iter.next(), Some(1));
-        assert_eq!(iter.next(), Some(2));
-        assert_eq!(iter.next_back(), Some(5));
-        assert_eq!(iter.next_back(), Some(4));
-        assert_eq!(iter.next(), Some(3));
-        assert_eq!(iter.next_back(), None);
+    #[test]
+    fn test_swap_relinks_nodes() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        let first = list.front().unwrap() as *const i32;
+
+        list.swap(0, 2);
+
+        assert_eq!(list.back().unwrap() as *const i32, first);
     }
 
     #[test]
-    fn test_cursor_move_next() {
+    #[should_panic]
+    fn test_swap_out_of_bounds() {
         let mut list = LinkedList::from([1, 2, 3]);
-        let mut cursor = list.cursor_front();
+        list.swap(0, 3);
+    }
 
-        assert_eq!(cursor.current(), Some(&1));
-        assert_eq!(cursor.index(), Some(0));
+    #[test]
+    fn test_clone() {
+        let list = LinkedList::from([1, 2, 3]);
+        let cloned = list.clone();
 
-        cursor.move_next();
+        assert_eq!(cloned, list);
+        assert_eq!(cloned.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+    }
 
-        assert_eq!(cursor.current(), Some(&2));
-        assert_eq!(cursor.index(), Some(1));
+    #[test]
+    fn test_clone_from() {
+        let source = LinkedList::from([1, 2, 3]);
+
+        let mut shorter = LinkedList::from([9]);
+        shorter.clone_from(&source);
+        assert_eq!(shorter, source);
+
+        let mut longer = LinkedList::from([9, 8, 7, 6, 5]);
+        let head = longer.front().unwrap() as *const i32;
+        longer.clone_from(&source);
+        assert_eq!(longer, source);
+        assert_eq!(longer.len(), 3);
+        assert_eq!(longer.back(), Some(&3));
+        assert_eq!(longer.front().unwrap() as *const i32, head);
+    }
 
-        cursor.move_next();
+    #[test]
+    fn test_clone_when_element_clone_panics() {
+        use std::rc::Rc;
 
-        assert_eq!(cursor.current(), Some(&3));
-        assert_eq!(cursor.index(), Some(2));
+        struct PanicOnClone(Rc<()>, bool);
 
-        cursor.move_next();
+        impl Clone for PanicOnClone {
+            fn clone(&self) -> Self {
+                if self.1 {
+                    panic!("clone panicked");
+                }
+                PanicOnClone(Rc::clone(&self.0), self.1)
+            }
+        }
 
-        assert_eq!(cursor.current(), None);
-        assert_eq!(cursor.index(), None);
+        let tracker = Rc::new(());
+        let list = LinkedList::from([
+            PanicOnClone(Rc::clone(&tracker), false),
+            PanicOnClone(Rc::clone(&tracker), false),
+            PanicOnClone(Rc::clone(&tracker), true),
+        ]);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| list.clone()));
+
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&tracker), 4);
+    }
+
+    #[test]
+    fn test_debug() {
+        let list = LinkedList::from([1, 2, 3]);
+        assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+        assert_eq!(format!("{:?}", LinkedList::<i32>::new()), "[]");
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(LinkedList::from([1, 2]), LinkedList::from([1, 2]));
+        assert_ne!(LinkedList::from([1, 2]), LinkedList::from([1, 2, 3]));
+        assert_ne!(LinkedList::from([1, 2]), LinkedList::from([2, 1]));
+        assert_ne!(LinkedList::from([f64::NAN]), LinkedList::from([f64::NAN]));
+    }
+
+    #[test]
+    fn test_ord() {
+        let list = LinkedList::from([1, 2]);
+        let greater = LinkedList::from([1, 3]);
+        let longer = LinkedList::from([1, 2, 0]);
+        let empty = LinkedList::new();
+
+        assert!(list < greater);
+        assert!(list < longer);
+        assert!(empty < list);
+        assert_eq!(LinkedList::from([2]).cmp(&LinkedList::from([1, 5])), Ordering::Greater);
+        assert_eq!(LinkedList::from([f64::NAN]).partial_cmp(&LinkedList::from([1.0])), None);
     }
 
     #[test]
-    fn test_cursor_move_back() {
-        let mut list = LinkedList::from([1, 2, 3]);
-        let mut cursor = list.cursor_back();
+    fn test_hash() {
+        use std::collections::hash_map::DefaultHasher;
 
-        assert_eq!(cursor.current(), Some(&3));
-        assert_eq!(cursor.index(), Some(2));
+        fn hash<H: Hash>(value: &H) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
 
-        cursor.move_prev();
+        let list = LinkedList::from([1, 2, 3]);
+        let std_list = std::collections::LinkedList::from([1, 2, 3]);
 
-        assert_eq!(cursor.current(), Some(&2));
-        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(hash(&list), hash(&std_list));
+        assert_eq!(hash(&list), hash(&list.clone()));
 
-        cursor.move_prev();
+        let nested = LinkedList::from([LinkedList::from([1]), LinkedList::from([])]);
+        let other = LinkedList::from([LinkedList::from([]), LinkedList::from([1])]);
+        assert_ne!(hash(&nested), hash(&other));
+    }
 
-        assert_eq!(cursor.current(), Some(&1));
-        assert_eq!(cursor.index(), Some(0));
+    #[test]
+    fn test_send_sync() {
+        fn assert_send<S: Send>() {}
+        fn assert_sync<S: Sync>() {}
+
+        assert_send::<LinkedList<i32>>();
+        assert_sync::<LinkedList<i32>>();
+        assert_send::<IntoIter<i32>>();
+        assert_sync::<IntoIter<i32>>();
+        assert_send::<Iter<'_, i32>>();
+        assert_sync::<Iter<'_, i32>>();
+        assert_send::<IterMut<'_, i32>>();
+        assert_sync::<IterMut<'_, i32>>();
+        assert_send::<Cursor<'_, i32>>();
+        assert_sync::<Cursor<'_, i32>>();
+        assert_send::<CursorMut<'_, i32>>();
+        assert_sync::<CursorMut<'_, i32>>();
+        assert_send::<Drain<'_, i32>>();
+        assert_sync::<Drain<'_, i32>>();
+
+        assert_send::<IterMut<'_, std::cell::Cell<i32>>>();
+        assert_send::<CursorMut<'_, std::cell::Cell<i32>>>();
+        assert_sync::<Iter<'_, std::sync::Mutex<i32>>>();
+    }
 
-        cursor.move_prev();
+    #[test]
+    fn test_send_to_thread() {
+        let mut list = LinkedList::from([1, 2, 3]);
 
-        assert_eq!(cursor.current(), None);
-        assert_eq!(cursor.index(), None);
+        std::thread::scope(|scope| {
+            scope.spawn(|| list.push_back(4));
+        });
+
+        let handle = std::thread::spawn(move || list.into_iter().sum::<i32>());
+        assert_eq!(handle.join().unwrap(), 10);
     }
 
     #[test]
-    fn test_cursor_peek_next() {
-        let mut list = LinkedList::from([1, 2, 3]);
-        let mut cursor = list.cursor_front();
+    fn test_variance() {
+        fn list<'a>(list: LinkedList<&'static str>) -> LinkedList<&'a str> {
+            list
+        }
 
-        assert_eq!(cursor.peek_next(), Some(&2));
+        fn into_iter<'a>(iter: IntoIter<&'static str>) -> IntoIter<&'a str> {
+            iter
+        }
 
-        cursor.move_next();
+        fn iter<'a, 'b>(iter: Iter<'a, &'static str>) -> Iter<'a, &'b str> {
+            iter
+        }
 
-        assert_eq!(cursor.peek_next(), Some(&3));
+        fn cursor<'a, 'b>(cursor: Cursor<'a, &'static str>) -> Cursor<'a, &'b str> {
+            cursor
+        }
 
-        cursor.move_next();
+        let value = String::from("value");
+        let mut shortened = list(LinkedList::from(["static"]));
+        shortened.push_back(&value);
 
-        assert_eq!(cursor.peek_next(), None);
+        assert_eq!(shortened.len(), 2);
+        assert_eq!(into_iter(LinkedList::from(["a"]).into_iter()).count(), 1);
+
+        let source = LinkedList::from(["b"]);
+        assert_eq!(iter(source.iter()).count(), 1);
+        assert_eq!(cursor(source.cursor_front()).current(), Some(&"b"));
     }
 
     #[test]
-    fn test_cursor_peek_prev() {
-        let mut list = LinkedList::from([1, 2, 3]);
-        let mut cursor = list.cursor_back();
-
-        assert_eq!(cursor.peek_prev(), Some(&2));
+    fn test_drop_check() {
+        struct PrintOnDrop<'a>(&'a String);
 
-        cursor.move_prev();
-
-        assert_eq!(cursor.peek_prev(), Some(&1));
+        impl Drop for PrintOnDrop<'_> {
+            fn drop(&mut self) {
+                assert_eq!(self.0, "value");
+            }
+        }
 
-        cursor.move_prev();
+        let value = String::from("value");
+        let mut list = LinkedList::new();
+        list.push_back(PrintOnDrop(&value));
 
-        assert_eq!(cursor.peek_prev(), None);
+        assert_eq!(list.len(), 1);
     }
 
     #[test]
-    fn test_cursor_mut_move_next() {
+    fn test_into_iter_for_references() {
         let mut list = LinkedList::from([1, 2, 3]);
-        let mut cursor = list.cursor_front_mut();
-
-        assert_eq!(cursor.current(), Some(&mut 1));
-        assert_eq!(cursor.index(), Some(0));
-
-        cursor.move_next();
 
-        assert_eq!(cursor.current(), Some(&mut 2));
-        assert_eq!(cursor.index(), Some(1));
+        for element in &mut list {
+            *element *= 10;
+        }
 
-        cursor.move_next();
+        let mut values = Vec::new();
+        for element in &list {
+            values.push(*element);
+        }
 
-        assert_eq!(cursor.current(), Some(&mut 3));
-        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(values, vec![10, 20, 30]);
+    }
 
-        cursor.move_next();
+    #[test]
+    fn test_extend() {
+        let mut list = LinkedList::from([1]);
+        list.extend(vec![2, 3]);
+        list.extend(&[4, 5]);
 
-        assert_eq!(cursor.current(), None);
-        assert_eq!(cursor.index(), None);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.back(), Some(&5));
     }
 
     #[test]
-    fn test_cursor_mut_move_back() {
-        let mut list = LinkedList::from([1, 2, 3]);
-        let mut cursor = list.cursor_back_mut();
+    fn test_extend_with_lists() {
+        let mut list = LinkedList::new();
+        let other = LinkedList::from([3, 4]);
+        let other_head = other.front().unwrap() as *const i32;
 
-        assert_eq!(cursor.current(), Some(&mut 3));
-        assert_eq!(cursor.index(), Some(2));
+        list.extend([LinkedList::from([1, 2]), LinkedList::new(), other]);
 
-        cursor.move_prev();
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.get(2).unwrap() as *const i32, other_head);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    }
 
-        assert_eq!(cursor.current(), Some(&mut 2));
-        assert_eq!(cursor.index(), Some(1));
+    #[test]
+    fn test_extend_with_list_elements() {
+        let mut list = LinkedList::from([1, 2]);
+        let other = LinkedList::from([3, 4]);
+        let other_head = other.front().unwrap() as *const i32;
 
-        cursor.move_prev();
+        list.extend(LinkedList::from([5]));
+        assert_eq!(list, [1, 2, 5]);
 
-        assert_eq!(cursor.current(), Some(&mut 1));
-        assert_eq!(cursor.index(), Some(0));
+        list.pop_back();
+        list.extend([other]);
+        assert_eq!(list.get(2).unwrap() as *const i32, other_head);
+        assert_eq!(list, [1, 2, 3, 4]);
+    }
 
-        cursor.move_prev();
+    #[test]
+    fn test_iter_size_hint() {
+        let mut list = LinkedList::from([1, 2, 3, 4]);
 
-        assert_eq!(cursor.current(), None);
-        assert_eq!(cursor.index(), None);
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 4);
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+
+        let mut iter_mut = list.iter_mut();
+        iter_mut.next();
+        assert_eq!(iter_mut.len(), 3);
+
+        let mut into_iter = list.into_iter();
+        assert_eq!(into_iter.len(), 4);
+        into_iter.next_back();
+        assert_eq!(into_iter.size_hint(), (3, Some(3)));
+        assert_eq!(into_iter.count(), 3);
     }
 
     #[test]
-    fn test_cursor_mut_peek_next() {
-        let mut list = LinkedList::from([1, 2, 3]);
-        let mut cursor = list.cursor_front_mut();
+    fn test_iter_fused() {
+        let list = LinkedList::from([1]);
+        let mut iter = list.iter();
 
-        assert_eq!(cursor.peek_next(), Some(&mut 2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
 
-        cursor.move_next();
+    #[test]
+    fn test_iter_nth() {
+        let list = (0..10).collect::<LinkedList<_>>();
 
-        assert_eq!(cursor.peek_next(), Some(&mut 3));
+        for n in 0..12 {
+            let mut iter = list.iter();
+            let mut expected = (0..10).collect::<Vec<_>>().into_iter();
 
-        cursor.move_next();
+            assert_eq!(iter.nth(n), expected.nth(n).as_ref());
+            assert_eq!(iter.len(), expected.len());
+            assert_eq!(iter.next(), expected.next().as_ref());
+            assert_eq!(iter.next_back(), expected.next_back().as_ref());
+        }
 
-        assert_eq!(cursor.peek_next(), None);
+        let mut iter = list.iter();
+        assert_eq!(iter.nth(2), Some(&2));
+        assert_eq!(iter.nth(5), Some(&8));
+        assert_eq!(iter.nth(1), None);
+        assert_eq!(iter.next(), None);
     }
 
     #[test]
-    fn test_cursor_mut_peek_prev() {
-        let mut list = LinkedList::from([1, 2, 3]);
-        let mut cursor = list.cursor_back_mut();
+    fn test_iter_nth_back() {
+        let list = (0..10).collect::<LinkedList<_>>();
 
-        assert_eq!(cursor.peek_prev(), Some(&mut 2));
-
-        cursor.move_prev();
+        for n in 0..12 {
+            let mut iter = list.iter();
+            let mut expected = (0..10).collect::<Vec<_>>().into_iter();
 
-        assert_eq!(cursor.peek_prev(), Some(&mut 1));
+            assert_eq!(iter.nth_back(n), expected.nth_back(n).as_ref());
+            assert_eq!(iter.len(), expected.len());
+            assert_eq!(iter.next(), expected.next().as_ref());
+            assert_eq!(iter.next_back(), expected.next_back().as_ref());
+        }
+    }
 
-        cursor.move_prev();
+    #[test]
+    fn test_into_iter_nth() {
+        for n in 0..12 {
+            let mut iter = (0..10).collect::<LinkedList<_>>().into_iter();
+            let mut expected = (0..10).collect::<Vec<_>>().into_iter();
+
+            assert_eq!(iter.nth(n), expected.nth(n));
+            assert_eq!(iter.len(), expected.len());
+            assert_eq!(iter.next(), expected.next());
+            assert_eq!(iter.next_back(), expected.next_back());
+
+            let mut iter = (0..10).collect::<LinkedList<_>>().into_iter();
+            let mut expected = (0..10).collect::<Vec<_>>().into_iter();
+
+            assert_eq!(iter.nth_back(n), expected.nth_back(n));
+            assert_eq!(iter.len(), expected.len());
+            assert_eq!(iter.next(), expected.next());
+            assert_eq!(iter.next_back(), expected.next_back());
+        }
 
-        assert_eq!(cursor.peek_prev(), None);
+        let shared = Rc::new(());
+        let mut iter = LinkedList::from_iter((0..5).map(|_| Rc::clone(&shared))).into_iter();
+        iter.nth(1);
+        assert_eq!(Rc::strong_count(&shared), 4);
+        iter.nth_back(0);
+        assert_eq!(Rc::strong_count(&shared), 3);
     }
 
     #[test]
-    fn test_cursor_mut_delete_head() {
-        let mut list = LinkedList::from([1, 2, 3]);
-        let mut cursor = list.cursor_front_mut();
+    fn test_iter_mut_nth() {
+        let mut list = (0..10).collect::<LinkedList<_>>();
 
-        let deleted = cursor.delete();
+        let mut iter = list.iter_mut();
+        *iter.nth(7).unwrap() = 70;
+        *iter.nth_back(1).unwrap() = 80;
+        assert_eq!(iter.len(), 0);
 
-        assert_eq!(deleted, Some(1));
+        assert_eq!(list.get(7), Some(&70));
+        assert_eq!(list.get(8), Some(&80));
+    }
 
-        assert_eq!(cursor.current(), Some(&mut 2));
-        assert_eq!(cursor.index(), Some(0));
+    #[test]
+    fn test_iter_last_count() {
+        let mut list = LinkedList::from([1, 2, 3]);
 
-        let values = list.into_iter().collect::<Vec<_>>();
+        assert_eq!(list.iter().last(), Some(&3));
+        assert_eq!(list.iter().count(), 3);
+        assert_eq!(list.iter_mut().last(), Some(&mut 3));
+        assert_eq!(list.iter_mut().count(), 3);
+        assert_eq!(list.into_iter().last(), Some(3));
+        assert_eq!(LinkedList::<i32>::new().iter().last(), None);
+    }
 
-        assert_eq!(values, vec![2, 3]);
+    #[test]
+    fn test_iter_fold() {
+        let mut list = LinkedList::from([1, 2, 3, 4]);
+
+        let mut iter = list.iter();
+        iter.next();
+        let push = |mut acc: Vec<i32>, x: &i32| {
+            acc.push(*x);
+            acc
+        };
+        assert_eq!(iter.clone().fold(Vec::new(), push), vec![2, 3, 4]);
+        assert_eq!(iter.rfold(Vec::new(), push), vec![4, 3, 2]);
+
+        let mut iter_mut = list.iter_mut();
+        iter_mut.next_back();
+        iter_mut.fold((), |_, x| *x *= 10);
+        list.iter_mut().rfold((), |_, x| *x += 1);
+
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![11, 21, 31, 5]);
     }
 
     #[test]
-    fn test_cursor_mut_delete_mid() {
+    fn test_iter_clone_and_debug() {
         let mut list = LinkedList::from([1, 2, 3]);
-        let mut cursor = list.cursor_front_mut();
 
-        cursor.move_next();
+        let mut iter = list.iter();
+        iter.next();
+        let cloned = iter.clone();
 
-        let deleted = cursor.delete();
-        assert_eq!(deleted, Some(2));
+        assert_eq!(iter.collect::<Vec<_>>(), cloned.collect::<Vec<_>>());
 
-        assert_eq!(cursor.current(), Some(&mut 3));
-        assert_eq!(cursor.index(), Some(1));
+        let mut iter = list.iter();
+        iter.next_back();
+        assert_eq!(format!("{:?}", iter), "Iter([1, 2])");
 
-        let values = list.into_iter().collect::<Vec<_>>();
+        let mut iter_mut = list.iter_mut();
+        iter_mut.next();
+        assert_eq!(format!("{:?}", iter_mut), "IterMut([2, 3])");
+    }
 
-        assert_eq!(values, vec![1, 3]);
+    #[test]
+    fn test_collect_preallocates() {
+        let list = (0..1000).collect::<LinkedList<_>>();
+
+        assert_eq!(list.iter().collect::<Vec<_>>().capacity(), 1000);
+        assert_eq!(list.into_iter().collect::<Vec<_>>().capacity(), 1000);
     }
 
     #[test]
-    fn test_cursor_mut_delete_tail() {
-        let mut list = LinkedList::from([1, 2, 3]);
-        let mut cursor = list.cursor_back_mut();
+    fn test_into_iter_into_list() {
+        let list = LinkedList::from([1, 2, 3, 4, 5]);
+        let mut iter = list.into_iter();
 
-        let deleted = cursor.delete();
-        assert_eq!(deleted, Some(3));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.as_list().len(), 3);
+        assert_eq!(format!("{:?}", iter), "IntoIter([2, 3, 4])");
 
-        assert_eq!(cursor.current(), None);
+        let mut rest = iter.into_list();
+        rest.push_back(6);
 
-        let values = list.into_iter().collect::<Vec<_>>();
+        assert_eq!(rest.into_iter().collect::<Vec<_>>(), vec![2, 3, 4, 6]);
+    }
 
-        assert_eq!(values, vec![1, 2]);
+    #[test]
+    fn test_from_collections() {
+        let from_vec = LinkedList::from(vec![1, 2, 3]);
+        let from_deque = LinkedList::from(VecDeque::from([1, 2, 3]));
+        let from_std = LinkedList::from(std::collections::LinkedList::from([1, 2, 3]));
+        let from_boxed = LinkedList::from(vec![1, 2, 3].into_boxed_slice());
+
+        assert_eq!(from_vec, [1, 2, 3]);
+        assert_eq!(from_deque, [1, 2, 3]);
+        assert_eq!(from_std, [1, 2, 3]);
+        assert_eq!(from_boxed, [1, 2, 3]);
+        assert_eq!(from_std.back(), Some(&3));
     }
 
     #[test]
-    fn test_cursor_mut_insert_before_when_current_empty() {
-        let mut list = LinkedList::from([2, 3]);
-        let mut cursor = list.cursor_front_mut();
+    fn test_into_collections() {
+        let vec = Vec::from((0..100).collect::<LinkedList<_>>());
+        assert_eq!(vec.capacity(), 100);
+        assert_eq!(vec, (0..100).collect::<Vec<_>>());
 
-        cursor.insert_before(1);
+        let boxed = Box::<[i32]>::from(LinkedList::from([1, 2, 3]));
+        assert_eq!(*boxed, [1, 2, 3]);
 
-        let values = list.into_iter().collect::<Vec<_>>();
+        let deque = VecDeque::from(LinkedList::from([1, 2, 3]));
+        assert_eq!(deque, [1, 2, 3]);
 
-        assert_eq!(values, vec![1, 2, 3]);
+        let std_list = std::collections::LinkedList::from(LinkedList::from([1, 2, 3]));
+        assert_eq!(std_list, std::collections::LinkedList::from([1, 2, 3]));
     }
 
     #[test]
-    fn test_cursor_mut_insert_after_when_prev_empty() {
-        let mut list = LinkedList::from([2, 3]);
-        let mut cursor = list.cursor_front_mut();
+    fn test_eq_slices() {
+        let list = LinkedList::from([1, 2, 3]);
+
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(list, vec![1, 2, 3]);
+        assert_eq!(list, &[1, 2, 3][..]);
+        assert_eq!(list, [1, 2, 3][..]);
+        assert_ne!(list, [1, 2]);
+        assert_ne!(list, [1, 2, 4]);
+        assert_eq!(LinkedList::<i32>::new(), []);
+    }
 
-        cursor.insert_before(1);
+    #[test]
+    fn test_list_macro() {
+        use std::rc::Rc;
 
-        assert_eq!(cursor.current(), Some(&mut 2));
-        assert_eq!(cursor.index(), Some(1));
+        let empty: LinkedList<i32> = list![];
+        assert!(empty.is_empty());
 
-        let values = list.into_iter().collect::<Vec<_>>();
+        assert_eq!(list![1], [1]);
+        assert_eq!(list![1, 2, 3,], [1, 2, 3]);
+        assert_eq!(list![0; 3], [0, 0, 0]);
+        assert_eq!(list![0; 0], []);
 
-        assert_eq!(values, vec![1, 2, 3]);
+        let shared = Rc::new(());
+        let repeated = list![Rc::clone(&shared); 3];
+        assert_eq!(repeated.len(), 3);
+        assert_eq!(Rc::strong_count(&shared), 4);
     }
 
     #[test]
-    fn test_cursor_mut_insert_before() {
-        let mut list = LinkedList::from([1, 3]);
-        let mut cursor = list.cursor_front_mut();
+    fn test_list_macro_clones() {
+        struct CloneCounter(Rc<Cell<usize>>);
 
-        cursor.move_next();
+        impl Clone for CloneCounter {
+            fn clone(&self) -> Self {
+                self.0.set(self.0.get() + 1);
+                CloneCounter(Rc::clone(&self.0))
+            }
+        }
 
-        cursor.insert_before(2);
+        let clones = Rc::new(Cell::new(0));
 
-        assert_eq!(cursor.current(), Some(&mut 3));
-        assert_eq!(cursor.index(), Some(2));
+        let repeated = list![CloneCounter(Rc::clone(&clones)); 3];
+        assert_eq!(repeated.len(), 3);
+        assert_eq!(clones.get(), 2);
 
-        let values = list.into_iter().collect::<Vec<_>>();
-        assert_eq!(values, vec![1, 2, 3]);
+        let empty = list![CloneCounter(Rc::clone(&clones)); 0];
+        assert!(empty.is_empty());
+        assert_eq!(clones.get(), 2);
+        assert_eq!(Rc::strong_count(&clones), 4);
     }
 
     #[test]
-    fn test_cursor_mut_insert_after_when_current_empty() {
-        let mut list = LinkedList::from([1, 2]);
-        let mut cursor = list.cursor_back_mut();
+    fn test_display() {
+        assert_eq!(LinkedList::from([1, 2, 3]).to_string(), "[1, 2, 3]");
+        assert_eq!(LinkedList::<i32>::new().to_string(), "[]");
+        assert_eq!(LinkedList::from([1.5]).to_string(), "[1.5]");
+    }
 
-        cursor.insert_after(3);
+    #[test]
+    fn test_display_with() {
+        let list = LinkedList::from(["a", "b", "c"]);
 
-        let values = list.into_iter().collect::<Vec<i32>>();
-        assert_eq!(values, vec![1, 2, 3]);
+        assert_eq!(list.display_with("", "", "").to_string(), "abc");
+        assert_eq!(list.display_with(" | ", "<", ">").to_string(), "<a | b | c>");
+        assert_eq!(format!("{:>2}", LinkedList::from([1, 2])), "[ 1,  2]");
     }
 
     #[test]
-    fn test_cursor_mut_insert_after_when_next_empty() {
-        let mut list = LinkedList::from([1, 2]);
-        let mut cursor = list.cursor_front_mut();
+    fn test_from_str() {
+        assert_eq!("[1, 2, 3]".parse::<LinkedList<i32>>(), Ok(LinkedList::from([1, 2, 3])));
+        assert_eq!("  [ 4 ,5,  6 ] ".parse::<LinkedList<i32>>(), Ok(LinkedList::from([4, 5, 6])));
+        assert_eq!("[]".parse::<LinkedList<i32>>(), Ok(LinkedList::new()));
+        assert_eq!("[ ]".parse::<LinkedList<i32>>(), Ok(LinkedList::new()));
+
+        let list = LinkedList::from([10, 20, 30]);
+        assert_eq!(list.to_string().parse::<LinkedList<i32>>(), Ok(list));
+    }
 
-        cursor.move_next();
+    #[test]
+    fn test_from_str_errors() {
+        assert_eq!("1, 2".parse::<LinkedList<i32>>(), Err(ParseListError::MissingBrackets));
+        assert_eq!("[1, 2".parse::<LinkedList<i32>>(), Err(ParseListError::MissingBrackets));
+
+        let err = "[1, 2, x]".parse::<LinkedList<i32>>().unwrap_err();
+        let expected = "x".parse::<i32>().unwrap_err();
+
+        assert_eq!(err, ParseListError::Element { index: 2, error: expected.clone() });
+        assert_eq!(
+            err.to_string(),
+            format!("failed to parse the element at index 2: {expected}")
+        );
+        assert!(err.source().is_some());
+
+        let err = "[1, , 3]".parse::<LinkedList<i32>>().unwrap_err();
+        assert!(matches!(err, ParseListError::Element { index: 1, .. }));
+    }
 
-        cursor.insert_after(3);
+    /// An element that counts its drops and can be told to panic when dropped.
+    struct DropCounter {
+        drops: Rc<Cell<usize>>,
+        panic: bool,
+    }
 
-        let values = list.into_iter().collect::<Vec<i32>>();
-        assert_eq!(values, vec![1, 2, 3]);
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+            if self.panic {
+                panic!("drop panicked");
+            }
+        }
+    }
+
+    fn drop_counters(
+        drops: &Rc<Cell<usize>>,
+        len: usize,
+        panic_at: usize,
+    ) -> LinkedList<DropCounter> {
+        (0..len)
+            .map(|index| DropCounter {
+                drops: Rc::clone(drops),
+                panic: index == panic_at,
+            })
+            .collect()
     }
 
     #[test]
-    fn test_cursor_mut_insert_after() {
-        let mut list = LinkedList::from([1, 3]);
-        let mut cursor = list.cursor_front_mut();
+    fn test_drop_when_element_drop_panics() {
+        let drops = Rc::new(Cell::new(0));
+        let list = drop_counters(&drops, 5, 1);
 
-        cursor.insert_after(2);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(list)));
 
-        let values = list.into_iter().collect::<Vec<i32>>();
-        assert_eq!(values, vec![1, 2, 3]);
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 5);
     }
-    
+
     #[test]
-    fn test_cursor_mut_insert_before_when_list_is_empty() {
-        let mut list = LinkedList::new();
-        let mut cursor = list.cursor_front_mut();
-        
-        cursor.insert_before(1);
-        let values = list.into_iter().collect::<Vec<i32>>();
-        assert_eq!(values, vec![1]);
+    fn test_clear_when_element_drop_panics() {
+        let drops = Rc::new(Cell::new(0));
+        let mut list = drop_counters(&drops, 5, 3);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| list.clear()));
+
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 5);
+        assert!(list.is_empty());
+        assert_eq!(list.front().map(|_| ()), None);
+        assert_eq!(list.back().map(|_| ()), None);
     }
 
     #[test]
-    fn test_cursor_mut_insert_after_when_list_is_empty() {
-        let mut list = LinkedList::new();
-        let mut cursor = list.cursor_front_mut();
+    fn test_from_iter_when_iterator_panics() {
+        let drops = Rc::new(Cell::new(0));
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            (0..5)
+                .map(|index| {
+                    if index == 3 {
+                        panic!("iterator panicked");
+                    }
+                    DropCounter {
+                        drops: Rc::clone(&drops),
+                        panic: false,
+                    }
+                })
+                .collect::<LinkedList<_>>()
+        }));
 
-        cursor.insert_after(1);
-        let values = list.into_iter().collect::<Vec<i32>>();
-        assert_eq!(values, vec![1]);
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 3);
     }
-    
+
     #[test]
-    fn test_cursor_mut_delete_when_list_has_only_one_element() {
-        let mut list = LinkedList::from([1]);
-        let mut cursor = list.cursor_front_mut();
-        
-        cursor.delete();
-        
-        assert!(list.is_empty());
+    fn test_from_array_drops_every_element() {
+        let drops = Rc::new(Cell::new(0));
+        let array = [0, 1, 2].map(|index| DropCounter {
+            drops: Rc::clone(&drops),
+            panic: index == 0,
+        });
+
+        let list = LinkedList::from(array);
+        assert_eq!(drops.get(), 0);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(list)));
+
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 3);
     }
 }
@@ -0,0 +1,97 @@
+//! Tests for the fallible allocation API.
+//!
+//! They live in their own test binary because they replace the global allocator with one
+//! that can be told to fail, which should not affect the rest of the test suite.
+
+use linked_list::{AllocError, InsertErrorKind, LinkedList};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    static FAIL_ALLOC: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The system allocator, failing every allocation on threads where `FAIL_ALLOC` is set.
+struct FailingAllocator;
+
+unsafe impl GlobalAlloc for FailingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if FAIL_ALLOC.try_with(Cell::get).unwrap_or(false) {
+            std::ptr::null_mut()
+        } else {
+            System.alloc(layout)
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: FailingAllocator = FailingAllocator;
+
+/// Resets `FAIL_ALLOC` when dropped, also while unwinding from a panic.
+struct ResetGuard;
+
+impl Drop for ResetGuard {
+    fn drop(&mut self) {
+        FAIL_ALLOC.with(|fail| fail.set(false));
+    }
+}
+
+/// Runs `f` with every allocation on the current thread failing.
+fn with_failing_alloc<R>(f: impl FnOnce() -> R) -> R {
+    let _guard = ResetGuard;
+    FAIL_ALLOC.with(|fail| fail.set(true));
+    f()
+}
+
+#[test]
+fn test_try_push() {
+    let mut list = LinkedList::new();
+
+    assert_eq!(list.try_push_back(2), Ok(()));
+    assert_eq!(list.try_push_front(1), Ok(()));
+
+    let back = with_failing_alloc(|| list.try_push_back(3));
+    let front = with_failing_alloc(|| list.try_push_front(0));
+
+    assert_eq!(back, Err(AllocError { element: 3 }));
+    assert_eq!(front, Err(AllocError { element: 0 }));
+    assert_eq!(list, [1, 2]);
+    assert_eq!(back.unwrap_err().to_string(), "memory allocation for a node failed");
+}
+
+#[test]
+fn test_try_insert_when_allocation_fails() {
+    let mut list = LinkedList::from([1, 3]);
+
+    let result = with_failing_alloc(|| list.try_insert(1, 2));
+
+    let err = result.unwrap_err();
+    assert_eq!(err.element, 2);
+    assert_eq!(err.kind, InsertErrorKind::AllocFailed);
+    assert_eq!(list, [1, 3]);
+
+    assert_eq!(list.try_insert(1, 2), Ok(()));
+    assert_eq!(list, [1, 2, 3]);
+}
+
+#[test]
+fn test_cursor_mut_try_insert() {
+    let mut list = LinkedList::from([2]);
+    let mut cursor = list.cursor_front_mut();
+
+    let before = with_failing_alloc(|| cursor.try_insert_before(1));
+    let after = with_failing_alloc(|| cursor.try_insert_after(3));
+
+    assert_eq!(before, Err(AllocError { element: 1 }));
+    assert_eq!(after, Err(AllocError { element: 3 }));
+    assert_eq!(cursor.index(), Some(0));
+
+    assert_eq!(cursor.try_insert_before(1), Ok(()));
+    assert_eq!(cursor.try_insert_after(3), Ok(()));
+    assert_eq!(cursor.index(), Some(1));
+    assert_eq!(list, [1, 2, 3]);
+}